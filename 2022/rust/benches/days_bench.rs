@@ -1,201 +0,0 @@
-use criterion::{criterion_group, criterion_main, Criterion};
-
-use adventofcode2022::days::day14;
-
-const INPUT: &str = concat!("490,63 -> 495,63\n",
-"489,159 -> 494,159\n",
-"481,56 -> 481,57 -> 495,57 -> 495,56\n",
-"485,157 -> 490,157\n",
-"478,157 -> 483,157\n",
-"498,69 -> 503,69\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"481,126 -> 481,128 -> 474,128 -> 474,134 -> 490,134 -> 490,128 -> 485,128 -> 485,126\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"497,63 -> 502,63\n",
-"487,66 -> 492,66\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"480,28 -> 480,29 -> 493,29 -> 493,28\n",
-"476,47 -> 476,50 -> 475,50 -> 475,54 -> 485,54 -> 485,50 -> 480,50 -> 480,47\n",
-"481,126 -> 481,128 -> 474,128 -> 474,134 -> 490,134 -> 490,128 -> 485,128 -> 485,126\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"494,78 -> 494,81 -> 491,81 -> 491,89 -> 505,89 -> 505,81 -> 498,81 -> 498,78\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"494,78 -> 494,81 -> 491,81 -> 491,89 -> 505,89 -> 505,81 -> 498,81 -> 498,78\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"480,28 -> 480,29 -> 493,29 -> 493,28\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"505,69 -> 510,69\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"459,173 -> 464,173\n",
-"476,47 -> 476,50 -> 475,50 -> 475,54 -> 485,54 -> 485,50 -> 480,50 -> 480,47\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"473,173 -> 478,173\n",
-"489,92 -> 489,94 -> 486,94 -> 486,97 -> 497,97 -> 497,94 -> 492,94 -> 492,92\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"481,155 -> 486,155\n",
-"479,161 -> 484,161\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"461,164 -> 466,164\n",
-"458,167 -> 463,167\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"501,66 -> 506,66\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"481,149 -> 481,150 -> 485,150 -> 485,149\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"489,92 -> 489,94 -> 486,94 -> 486,97 -> 497,97 -> 497,94 -> 492,94 -> 492,92\n",
-"475,159 -> 480,159\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"504,44 -> 508,44\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"486,38 -> 490,38\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"482,74 -> 482,75 -> 494,75 -> 494,74\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"472,161 -> 477,161\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"465,167 -> 470,167\n",
-"489,92 -> 489,94 -> 486,94 -> 486,97 -> 497,97 -> 497,94 -> 492,94 -> 492,92\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"476,47 -> 476,50 -> 475,50 -> 475,54 -> 485,54 -> 485,50 -> 480,50 -> 480,47\n",
-"486,44 -> 490,44\n",
-"481,126 -> 481,128 -> 474,128 -> 474,134 -> 490,134 -> 490,128 -> 485,128 -> 485,126\n",
-"489,92 -> 489,94 -> 486,94 -> 486,97 -> 497,97 -> 497,94 -> 492,94 -> 492,92\n",
-"480,28 -> 480,29 -> 493,29 -> 493,28\n",
-"466,173 -> 471,173\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"494,78 -> 494,81 -> 491,81 -> 491,89 -> 505,89 -> 505,81 -> 498,81 -> 498,78\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"482,74 -> 482,75 -> 494,75 -> 494,74\n",
-"480,44 -> 484,44\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"482,159 -> 487,159\n",
-"474,155 -> 479,155\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"492,44 -> 496,44\n",
-"486,161 -> 491,161\n",
-"481,126 -> 481,128 -> 474,128 -> 474,134 -> 490,134 -> 490,128 -> 485,128 -> 485,126\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"481,149 -> 481,150 -> 485,150 -> 485,149\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"483,41 -> 487,41\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"465,161 -> 470,161\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"481,56 -> 481,57 -> 495,57 -> 495,56\n",
-"489,92 -> 489,94 -> 486,94 -> 486,97 -> 497,97 -> 497,94 -> 492,94 -> 492,92\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"498,38 -> 502,38\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"469,170 -> 474,170\n",
-"476,47 -> 476,50 -> 475,50 -> 475,54 -> 485,54 -> 485,50 -> 480,50 -> 480,47\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"489,35 -> 493,35\n",
-"477,153 -> 482,153\n",
-"494,66 -> 499,66\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"495,41 -> 499,41\n",
-"494,78 -> 494,81 -> 491,81 -> 491,89 -> 505,89 -> 505,81 -> 498,81 -> 498,78\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"491,69 -> 496,69\n",
-"484,69 -> 489,69\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"489,92 -> 489,94 -> 486,94 -> 486,97 -> 497,97 -> 497,94 -> 492,94 -> 492,92\n",
-"481,126 -> 481,128 -> 474,128 -> 474,134 -> 490,134 -> 490,128 -> 485,128 -> 485,126\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"493,60 -> 498,60\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"471,157 -> 476,157\n",
-"481,126 -> 481,128 -> 474,128 -> 474,134 -> 490,134 -> 490,128 -> 485,128 -> 485,126\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"492,32 -> 496,32\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"494,78 -> 494,81 -> 491,81 -> 491,89 -> 505,89 -> 505,81 -> 498,81 -> 498,78\n",
-"455,170 -> 460,170\n",
-"494,78 -> 494,81 -> 491,81 -> 491,89 -> 505,89 -> 505,81 -> 498,81 -> 498,78\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"495,35 -> 499,35\n",
-"493,161 -> 498,161\n",
-"462,170 -> 467,170\n",
-"482,74 -> 482,75 -> 494,75 -> 494,74\n",
-"476,47 -> 476,50 -> 475,50 -> 475,54 -> 485,54 -> 485,50 -> 480,50 -> 480,47\n",
-"494,78 -> 494,81 -> 491,81 -> 491,89 -> 505,89 -> 505,81 -> 498,81 -> 498,78\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"481,126 -> 481,128 -> 474,128 -> 474,134 -> 490,134 -> 490,128 -> 485,128 -> 485,126\n",
-"476,47 -> 476,50 -> 475,50 -> 475,54 -> 485,54 -> 485,50 -> 480,50 -> 480,47\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"491,23 -> 491,17 -> 491,23 -> 493,23 -> 493,14 -> 493,23 -> 495,23 -> 495,14 -> 495,23 -> 497,23 -> 497,21 -> 497,23 -> 499,23 -> 499,18 -> 499,23 -> 501,23 -> 501,13 -> 501,23 -> 503,23 -> 503,16 -> 503,23 -> 505,23 -> 505,22 -> 505,23 -> 507,23 -> 507,14 -> 507,23\n",
-"498,44 -> 502,44\n",
-"484,147 -> 484,146 -> 484,147 -> 486,147 -> 486,141 -> 486,147 -> 488,147 -> 488,139 -> 488,147 -> 490,147 -> 490,144 -> 490,147 -> 492,147 -> 492,143 -> 492,147 -> 494,147 -> 494,144 -> 494,147 -> 496,147 -> 496,137 -> 496,147\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n",
-"492,38 -> 496,38\n",
-"489,92 -> 489,94 -> 486,94 -> 486,97 -> 497,97 -> 497,94 -> 492,94 -> 492,92\n",
-"481,56 -> 481,57 -> 495,57 -> 495,56\n",
-"476,47 -> 476,50 -> 475,50 -> 475,54 -> 485,54 -> 485,50 -> 480,50 -> 480,47\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"501,41 -> 505,41\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"452,173 -> 457,173\n",
-"489,41 -> 493,41\n",
-"468,159 -> 473,159\n",
-"481,149 -> 481,150 -> 485,150 -> 485,149\n",
-"468,123 -> 468,113 -> 468,123 -> 470,123 -> 470,115 -> 470,123 -> 472,123 -> 472,122 -> 472,123 -> 474,123 -> 474,118 -> 474,123 -> 476,123 -> 476,121 -> 476,123 -> 478,123 -> 478,114 -> 478,123 -> 480,123 -> 480,116 -> 480,123 -> 482,123 -> 482,121 -> 482,123\n",
-"477,110 -> 477,108 -> 477,110 -> 479,110 -> 479,101 -> 479,110 -> 481,110 -> 481,102 -> 481,110 -> 483,110 -> 483,100 -> 483,110 -> 485,110 -> 485,101 -> 485,110 -> 487,110 -> 487,109 -> 487,110 -> 489,110 -> 489,101 -> 489,110 -> 491,110 -> 491,106 -> 491,110\n");
-
-pub fn day14_part_1_benchmark(c: &mut Criterion) {
-    c.bench_function("day 14 part 1", |b| {
-        b.iter(|| day14::solve_part_one(&INPUT))
-    });
-}
-pub fn day14_part_2_benchmark(c: &mut Criterion) {
-    let mut group = c.benchmark_group("day 14 part 2");
-    group.sample_size(10);
-    group.bench_function("day 14 part 2 solve", |b| b.iter(||day14::solve_part_two(&INPUT)));
-    group.finish();
-}
-
-criterion_group!(benches, day14_part_1_benchmark, day14_part_2_benchmark);
-criterion_main!(benches);
@@ -1,21 +1,61 @@
-use std::{fs, path::PathBuf};
-
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
-
-    let mut calories_per_elf = input
-        .split("\n\n")
-        .map(|group| {
-            group
-                .lines()
-                .map(|l| l.parse::<i32>().unwrap())
-                .sum::<i32>()
-        })
-        .collect::<Vec<i32>>();
-    calories_per_elf.sort_unstable();
-
-    let max_calories = calories_per_elf.last().expect("There has to be an element");
-    let sum_max_three: i32 = calories_per_elf.iter().rev().take(3).sum();
-
-    (max_calories.to_string(), sum_max_three.to_string())
+use aoc_common::{Answer, Solution, SolveError};
+
+pub struct Day01;
+
+aoc_common::register!(Day01);
+
+impl Solution for Day01 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Calorie Counting";
+
+    type Input = Vec<i32>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let mut calories_per_elf = vec![0];
+        for (i, l) in input.lines().enumerate() {
+            if l.is_empty() {
+                calories_per_elf.push(0);
+                continue;
+            }
+
+            let calories = l
+                .parse::<i32>()
+                .map_err(|_| SolveError::at(i, 0, format!("invalid calories '{l}'")))?;
+            *calories_per_elf.last_mut().expect("there is always an elf") += calories;
+        }
+        calories_per_elf.sort_unstable();
+
+        Ok(calories_per_elf)
+    }
+
+    fn part_one(&self, calories_per_elf: &Self::Input) -> Result<Answer, SolveError> {
+        let max_calories = calories_per_elf
+            .last()
+            .ok_or_else(|| SolveError::new("there has to be an elf"))?;
+
+        Ok((*max_calories).into())
+    }
+
+    fn part_two(&self, calories_per_elf: &Self::Input) -> Result<Answer, SolveError> {
+        let sum_max_three: i32 = calories_per_elf.iter().rev().take(3).sum();
+
+        Ok(sum_max_three.into())
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "1000\n", "2000\n", "3000\n", "\n", "4000\n", "\n", "5000\n", "6000\n", "\n", "7000\n",
+    "8000\n", "9000\n", "\n", "10000\n",
+);
+
+aoc_common::examples! {
+    solution: Day01,
+    part_one: {
+        EXAMPLE => "24000",
+    },
+    part_two: {
+        EXAMPLE => "45000",
+    },
 }
@@ -1,4 +1,4 @@
-use std::{fs, path::PathBuf};
+use aoc_common::{Answer, Solution, SolveError};
 
 /*
 --- Day 10: Cathode-Ray Tube ---
@@ -324,83 +324,257 @@ Allowing the program to run to completion causes the CRT to produce the followin
 Render the image given by your program. What eight capital letters appear on your CRT?
 */
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).unwrap();
+pub struct Day10;
 
-    let instructions = parse_instructions(&input);
-    let part_one = solve_part_one(&instructions);
+aoc_common::register!(Day10);
 
-    (part_one.to_string(), String::new())
+impl Solution for Day10 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 10;
+    const TITLE: &'static str = "Cathode-Ray Tube";
+
+    type Input = Vec<Instruction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_instructions(input)
+    }
+
+    fn part_one(&self, instructions: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(instructions).into())
+    }
+
+    fn part_two(&self, instructions: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(instructions))
+    }
 }
 
-fn solve_part_one(instructions: &Vec<Instruction>) -> i32 {
-    let mut x: i32 = 1;
-    let mut cycle: i32 = 1;
-    let mut signal_strength = 0;
-    let mut inspection_interval = 20;
+fn solve_part_one(instructions: &[Instruction]) -> i32 {
+    register_values(instructions)
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| (i as i32 + 1, x))
+        .filter(|(cycle, _)| cycle % 40 == 20)
+        .map(|(cycle, x)| cycle * x)
+        .sum()
+}
 
-    let mut crt: Vec<Vec<bool>> = (0..6).map(|_| (0..40).map(|_| false).collect()).collect();
+fn solve_part_two(instructions: &[Instruction]) -> Answer {
+    let pixels: Vec<char> = register_values(instructions)
+        .iter()
+        .enumerate()
+        .map(|(i, &x)| match (i as i32 % 40).abs_diff(x) <= 1 {
+            true => '#',
+            false => '.',
+        })
+        .collect();
+
+    Answer::picture(pixels.chunks(40).map(String::from_iter))
+}
+
+/// Value of the X register during every cycle, starting with the first one.
+fn register_values(instructions: &[Instruction]) -> Vec<i32> {
+    let mut x: i32 = 1;
+    let mut values = Vec::with_capacity(instructions.len() * 2);
     for instruction in instructions {
-        let wait_time = match instruction {
-            Instruction::AddX(_) => 2,
-            Instruction::Noop => 1,
-        };
-
-        for _ in 0..wait_time {
-            let cycle_zero_based: usize = (cycle - 1).try_into().unwrap();
-            let crt_x: usize = cycle_zero_based % 40;
-            let crt_y: usize = cycle_zero_based / 40;
-            crt[crt_y][crt_x] = crt_x.abs_diff(x as usize) <= 1;
-            // println!("{cycle:3}: {x:3} ({signal_strength:4}) | {instruction:?}");
-
-            if cycle == inspection_interval {
-                signal_strength += cycle * x;
-                inspection_interval += 40;
+        match instruction {
+            Instruction::AddX(a) => {
+                values.extend([x, x]);
+                x += a;
             }
-
-            cycle += 1;
+            Instruction::Noop => values.push(x),
         }
-
-        x = match instruction {
-            Instruction::AddX(a) => x + a,
-            Instruction::Noop => x,
-        };
     }
 
-    draw_crt(&crt);
-
-    return signal_strength;
+    values
 }
 
 #[derive(Debug)]
-enum Instruction {
+pub enum Instruction {
     AddX(i32),
     Noop,
 }
 
-fn draw_crt(crt: &Vec<Vec<bool>>) {
-    for row in crt {
-        for &cell in row {
-            let pixel = match cell {
-                true => "#",
-                false => ".",
-            };
-            print!("{pixel}");
-        }
-        println!();
-    }
-}
-
-fn parse_instructions(input: &str) -> Vec<Instruction> {
+fn parse_instructions(input: &str) -> Result<Vec<Instruction>, SolveError> {
     input
         .lines()
-        .map(|l| {
+        .enumerate()
+        .map(|(i, l)| {
             let parts = l.split(" ").collect::<Vec<_>>();
-            match parts[0] {
-                "addx" => Instruction::AddX(parts[1].parse::<i32>().unwrap()),
-                "noop" => Instruction::Noop,
-                _ => panic!("unknown instruction {l}"),
+            match parts[..] {
+                ["addx", n] => n
+                    .parse::<i32>()
+                    .map(Instruction::AddX)
+                    .map_err(|_| SolveError::at_token(i, l, n, format!("invalid number '{n}'"))),
+                ["noop"] => Ok(Instruction::Noop),
+                _ => Err(SolveError::at(i, 0, format!("unknown instruction {l}"))),
             }
         })
         .collect()
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "addx 15\n",
+    "addx -11\n",
+    "addx 6\n",
+    "addx -3\n",
+    "addx 5\n",
+    "addx -1\n",
+    "addx -8\n",
+    "addx 13\n",
+    "addx 4\n",
+    "noop\n",
+    "addx -1\n",
+    "addx 5\n",
+    "addx -1\n",
+    "addx 5\n",
+    "addx -1\n",
+    "addx 5\n",
+    "addx -1\n",
+    "addx 5\n",
+    "addx -1\n",
+    "addx -35\n",
+    "addx 1\n",
+    "addx 24\n",
+    "addx -19\n",
+    "addx 1\n",
+    "addx 16\n",
+    "addx -11\n",
+    "noop\n",
+    "noop\n",
+    "addx 21\n",
+    "addx -15\n",
+    "noop\n",
+    "noop\n",
+    "addx -3\n",
+    "addx 9\n",
+    "addx 1\n",
+    "addx -3\n",
+    "addx 8\n",
+    "addx 1\n",
+    "addx 5\n",
+    "noop\n",
+    "noop\n",
+    "noop\n",
+    "noop\n",
+    "noop\n",
+    "addx -36\n",
+    "noop\n",
+    "addx 1\n",
+    "addx 7\n",
+    "noop\n",
+    "noop\n",
+    "noop\n",
+    "addx 2\n",
+    "addx 6\n",
+    "noop\n",
+    "noop\n",
+    "noop\n",
+    "noop\n",
+    "noop\n",
+    "addx 1\n",
+    "noop\n",
+    "noop\n",
+    "addx 7\n",
+    "addx 1\n",
+    "noop\n",
+    "addx -13\n",
+    "addx 13\n",
+    "addx 7\n",
+    "noop\n",
+    "addx 1\n",
+    "addx -33\n",
+    "noop\n",
+    "noop\n",
+    "noop\n",
+    "addx 2\n",
+    "noop\n",
+    "noop\n",
+    "noop\n",
+    "addx 8\n",
+    "noop\n",
+    "addx -1\n",
+    "addx 2\n",
+    "addx 1\n",
+    "noop\n",
+    "addx 17\n",
+    "addx -9\n",
+    "addx 1\n",
+    "addx 1\n",
+    "addx -3\n",
+    "addx 11\n",
+    "noop\n",
+    "noop\n",
+    "addx 1\n",
+    "noop\n",
+    "addx 1\n",
+    "noop\n",
+    "noop\n",
+    "addx -13\n",
+    "addx -19\n",
+    "addx 1\n",
+    "addx 3\n",
+    "addx 26\n",
+    "addx -30\n",
+    "addx 12\n",
+    "addx -1\n",
+    "addx 3\n",
+    "addx 1\n",
+    "noop\n",
+    "noop\n",
+    "noop\n",
+    "addx -9\n",
+    "addx 18\n",
+    "addx 1\n",
+    "addx 2\n",
+    "noop\n",
+    "noop\n",
+    "addx 9\n",
+    "noop\n",
+    "noop\n",
+    "noop\n",
+    "addx -1\n",
+    "addx 2\n",
+    "addx -37\n",
+    "addx 1\n",
+    "addx 3\n",
+    "noop\n",
+    "addx 15\n",
+    "addx -21\n",
+    "addx 22\n",
+    "addx -6\n",
+    "addx 1\n",
+    "noop\n",
+    "addx 2\n",
+    "addx 1\n",
+    "noop\n",
+    "addx -10\n",
+    "noop\n",
+    "noop\n",
+    "addx 20\n",
+    "addx 1\n",
+    "addx 2\n",
+    "addx 2\n",
+    "addx -6\n",
+    "addx -11\n",
+    "noop\n",
+    "noop\n",
+    "noop\n",
+);
+
+aoc_common::examples! {
+    solution: Day10,
+    part_one: {
+        EXAMPLE => "13140",
+    },
+    part_two: {
+        EXAMPLE => concat!(
+            "##..##..##..##..##..##..##..##..##..##..\n",
+            "###...###...###...###...###...###...###.\n",
+            "####....####....####....####....####....\n",
+            "#####.....#####.....#####.....#####.....\n",
+            "######......######......######......####\n",
+            "#######.......#######.......#######.....",
+        ),
+    },
+}
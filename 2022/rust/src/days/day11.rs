@@ -1,4 +1,10 @@
-use std::{collections::VecDeque, fs, path::PathBuf};
+use std::collections::VecDeque;
+
+use aoc_common::{
+    input::{self, Line},
+    parse::Cursor,
+    Answer, Param, Params, Solution, SolveError,
+};
 
 /*
 --- Day 11: Monkey in the Middle ---
@@ -307,18 +313,67 @@ After 10000 rounds, the two most active monkeys inspected items 52166 and 52013
 Worry levels are no longer divided by three after each item is inspected; you'll need to find another way to keep your worry levels manageable. Starting again from the initial state in your puzzle input, what is the level of monkey business after 10000 rounds?
 */
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).unwrap();
+pub struct Day11 {
+    /// Rounds after which the monkey business is measured in part one.
+    pub rounds_part_one: usize,
+    /// Rounds after which the monkey business is measured in part two.
+    pub rounds_part_two: usize,
+}
+
+impl Day11 {
+    pub const PUZZLE: Day11 = Day11 {
+        rounds_part_one: 20,
+        rounds_part_two: 10_000,
+    };
+}
+
+aoc_common::register!(Day11::PUZZLE);
+
+impl Solution for Day11 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Monkey in the Middle";
+
+    type Input = Vec<Notes>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_notes(input)
+    }
+
+    fn part_one(&self, notes: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(notes, self.rounds_part_one).into())
+    }
+
+    fn part_two(&self, notes: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(notes, self.rounds_part_two).into())
+    }
 
-    let part_one = solve_part_one(&input);
-    let part_two = solve_part_two(&input);
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param {
+                name: "rounds_part_one",
+                value: self.rounds_part_one as i64,
+                description: "rounds of part one",
+            },
+            Param {
+                name: "rounds_part_two",
+                value: self.rounds_part_two as i64,
+                description: "rounds of part two",
+            },
+        ]
+    }
 
-    (part_one.to_string(), part_two.to_string())
+    fn with_params(&self, params: &Params) -> Result<Self, SolveError> {
+        Ok(Day11 {
+            rounds_part_one: params.get("rounds_part_one", self.rounds_part_one)?,
+            rounds_part_two: params.get("rounds_part_two", self.rounds_part_two)?,
+        })
+    }
 }
 
-fn solve_part_one(input: &str) -> u64 {
-    let mut monkeys = parse_monkeys(&input, 3, false);
-    run_rounds(20, &mut monkeys);
+fn solve_part_one(notes: &[Notes], rounds: usize) -> u64 {
+    let mut monkeys = build_monkeys(notes, 3, false);
+    run_rounds(rounds, &mut monkeys);
 
     let mut counts: Vec<u64> = monkeys.iter().map(|m| m.inspection_count).collect();
     counts.sort();
@@ -326,9 +381,9 @@ fn solve_part_one(input: &str) -> u64 {
     counts.iter().rev().take(2).product()
 }
 
-fn solve_part_two(input: &str) -> u64 {
-    let mut monkeys = parse_monkeys(&input, 1, true);
-    run_rounds(10_000, &mut monkeys);
+fn solve_part_two(notes: &[Notes], rounds: usize) -> u64 {
+    let mut monkeys = build_monkeys(notes, 1, true);
+    run_rounds(rounds, &mut monkeys);
 
     let mut counts: Vec<u64> = monkeys.iter().map(|m| m.inspection_count).collect();
     counts.sort();
@@ -336,12 +391,12 @@ fn solve_part_two(input: &str) -> u64 {
     counts.iter().rev().take(2).product()
 }
 
-fn run_rounds(number: usize, monkeys: &mut Vec<Monkey>) {
+fn run_rounds(number: usize, monkeys: &mut [Monkey]) {
     for _ in 0..number {
         run_round(monkeys);
     }
 
-    fn run_round(monkeys: &mut Vec<Monkey>) {
+    fn run_round(monkeys: &mut [Monkey]) {
         for i in 0..monkeys.len() {
             monkeys[i].inspect_items();
             while let Some(throw) = monkeys[i].perform_throw() {
@@ -351,78 +406,103 @@ fn run_rounds(number: usize, monkeys: &mut Vec<Monkey>) {
     }
 }
 
-fn parse_monkeys(input: &str, worry_divisor: u64, use_mod: bool) -> Vec<Monkey> {
-    struct Params {
-        number: u8,
-        operation: Operation,
-        test: Test,
-        items: VecDeque<u64>,
+/// What the notes say about a single monkey before any round is played.
+#[derive(Debug)]
+pub struct Notes {
+    number: u8,
+    operation: Operation,
+    test: Test,
+    items: VecDeque<u64>,
+}
+
+fn parse_notes(input: &str) -> Result<Vec<Notes>, SolveError> {
+    let mut notes = Vec::new();
+
+    for block in input::blocks(input) {
+        let [header, items, operation, test, if_true, if_false] = block.lines() else {
+            return Err(block.error("notes of a monkey must have six lines"));
+        };
+
+        let mut cursor = header.cursor();
+        cursor.expect("Monkey ")?;
+        let number_start = cursor;
+        let number: u8 = cursor.number()?;
+        if number as usize != notes.len() {
+            return Err(number_start.error(format!("expected monkey {}", notes.len())));
+        }
+        cursor.expect(":")?;
+        cursor.end()?;
+
+        let mut cursor = items.value_of("Starting items")?;
+        let items = cursor.separated(", ", Cursor::number)?.into();
+        cursor.end()?;
+
+        let mut cursor = operation.value_of("Operation")?;
+        cursor.expect("new = old ")?;
+        let operation = parse_operation(&mut cursor)?;
+        cursor.end()?;
+
+        let mut cursor = test.value_of("Test")?;
+        cursor.expect("divisible by ")?;
+        let divisor_start = cursor;
+        let divisible_by: u64 = cursor.number()?;
+        if divisible_by == 0 {
+            return Err(divisor_start.error("divisor must not be 0"));
+        }
+        cursor.end()?;
+
+        notes.push(Notes {
+            number,
+            operation,
+            test: Test::new(
+                divisible_by,
+                parse_target(if_true, "If true")?,
+                parse_target(if_false, "If false")?,
+            ),
+            items,
+        });
     }
 
-    let params = input
-        .split("\n\n")
-        .map(|part| {
-            let lines: Vec<&str> = part.lines().collect();
-
-            let number = lines[0]
-                .split(" ")
-                .last()
-                .unwrap()
-                .trim_end_matches(':')
-                .parse::<u8>()
-                .unwrap();
-
-            let items: VecDeque<u64> = lines[1]
-                .split(": ")
-                .last()
-                .unwrap()
-                .split(", ")
-                .map(|s| s.parse::<u64>().unwrap())
-                .collect();
-
-            let raw_operation: Vec<&str> =
-                lines[2].split("= ").last().unwrap().split(" ").collect();
-
-            let operation = match raw_operation[..] {
-                ["old", "*", "old"] => Operation::Square,
-                ["old", "*", n] => Operation::Multiply(n.parse().unwrap()),
-                ["old", "+", n] => Operation::Add(n.parse().unwrap()),
-                _ => panic!("cannot parse operation {raw_operation:?}"),
-            };
+    let monkey_count = notes.len();
+    if let Some(n) = notes.iter().find(|n| {
+        n.test.if_true as usize >= monkey_count || n.test.if_false as usize >= monkey_count
+    }) {
+        return Err(SolveError::new(format!(
+            "monkey {} throws to a monkey that does not exist",
+            n.number
+        )));
+    }
 
-            let divisible_by = lines[3]
-                .split("by ")
-                .last()
-                .unwrap()
-                .parse::<u64>()
-                .unwrap();
-
-            let if_true = lines[4]
-                .split("monkey ")
-                .last()
-                .unwrap()
-                .parse::<u8>()
-                .unwrap();
-
-            let if_false = lines[5]
-                .split("monkey ")
-                .last()
-                .unwrap()
-                .parse::<u8>()
-                .unwrap();
-
-            Params {
-                number,
-                operation,
-                test: Test::new(divisible_by, if_true, if_false),
-                items,
-            }
-        })
-        .collect::<Vec<_>>();
+    Ok(notes)
+}
+
+/// Operation after `new = old `, e.g. `* 19`.
+fn parse_operation(cursor: &mut Cursor) -> Result<Operation, SolveError> {
+    let operator_start = *cursor;
+    let operation = match cursor.until(" ")? {
+        "*" if cursor.eat("old") => Operation::Square,
+        "*" => Operation::Multiply(cursor.number()?),
+        "+" => Operation::Add(cursor.number()?),
+        _ => return Err(operator_start.error("expected '*' or '+'")),
+    };
+
+    Ok(operation)
+}
 
-    let mod_base = params.iter().map(|p| p.test.divisor).product::<u64>() * worry_divisor;
+/// Monkey an item is thrown to, e.g. `If true: throw to monkey 2`.
+fn parse_target(line: &Line, key: &str) -> Result<u8, SolveError> {
+    let mut cursor = line.value_of(key)?;
+    cursor.expect("throw to monkey ")?;
+    let monkey = cursor.number()?;
+    cursor.end()?;
 
-    params
+    Ok(monkey)
+}
+
+fn build_monkeys(notes: &[Notes], worry_divisor: u64, use_mod: bool) -> Vec<Monkey> {
+    let mod_base = notes.iter().map(|p| p.test.divisor).product::<u64>() * worry_divisor;
+
+    notes
         .iter()
         .map(|p| {
             Monkey::new(
@@ -545,3 +625,44 @@ impl Monkey {
         self.items.push_back(item)
     }
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "Monkey 0:\n",
+    "  Starting items: 79, 98\n",
+    "  Operation: new = old * 19\n",
+    "  Test: divisible by 23\n",
+    "    If true: throw to monkey 2\n",
+    "    If false: throw to monkey 3\n",
+    "\n",
+    "Monkey 1:\n",
+    "  Starting items: 54, 65, 75, 74\n",
+    "  Operation: new = old + 6\n",
+    "  Test: divisible by 19\n",
+    "    If true: throw to monkey 2\n",
+    "    If false: throw to monkey 0\n",
+    "\n",
+    "Monkey 2:\n",
+    "  Starting items: 79, 60, 97\n",
+    "  Operation: new = old * old\n",
+    "  Test: divisible by 13\n",
+    "    If true: throw to monkey 1\n",
+    "    If false: throw to monkey 3\n",
+    "\n",
+    "Monkey 3:\n",
+    "  Starting items: 74\n",
+    "  Operation: new = old + 3\n",
+    "  Test: divisible by 17\n",
+    "    If true: throw to monkey 0\n",
+    "    If false: throw to monkey 1\n",
+);
+
+aoc_common::examples! {
+    solution: Day11::PUZZLE,
+    part_one: {
+        EXAMPLE => "10605",
+    },
+    part_two: {
+        EXAMPLE => "2713310158",
+    },
+}
@@ -1,17 +1,36 @@
-use core::panic;
-use std::{cell::RefCell, fmt::Display, fs, path::PathBuf, rc::Rc, str::Chars};
+use std::{cell::RefCell, fmt::Display, rc::Rc};
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).unwrap();
-    let part_one = solve_part_one(&input);
-    let part_two = solve_part_two(&input);
+use aoc_common::{
+    input::{self, Line},
+    parse::Cursor,
+    Answer, Solution, SolveError,
+};
 
-    return (part_one.to_string(), part_two.to_string());
-}
+pub struct Day13;
+
+aoc_common::register!(Day13);
+
+impl Solution for Day13 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 13;
+    const TITLE: &'static str = "Distress Signal";
+
+    type Input = Vec<PacketPair>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_pairs(input)
+    }
+
+    fn part_one(&self, pairs: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(pairs).into())
+    }
 
-fn solve_part_one(input: &str) -> usize {
-    let pairs = parse_pairs(input);
+    fn part_two(&self, pairs: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(pairs).into())
+    }
+}
 
+fn solve_part_one(pairs: &[PacketPair]) -> usize {
     pairs
         .iter()
         .enumerate()
@@ -26,18 +45,14 @@ fn solve_part_one(input: &str) -> usize {
         .sum()
 }
 
-fn solve_part_two(input: &str) -> usize {
-    let divider_one = parse_packet_data("[[2]]");
-    let divider_two = parse_packet_data("[[6]]");
-    let mut packets = input
-        .lines()
-        .filter_map(|l| {
-            if l.is_empty() {
-                return None;
-            }
-
-            Some(parse_packet_data(l))
-        })
+fn solve_part_two(pairs: &[PacketPair]) -> usize {
+    let divider =
+        |text| parse_packet_data(&Line { index: 0, text }).expect("divider packets are valid");
+    let divider_one = divider("[[2]]");
+    let divider_two = divider("[[6]]");
+    let mut packets = pairs
+        .iter()
+        .flat_map(|(l, r)| [Rc::clone(l), Rc::clone(r)])
         .chain([Rc::clone(&divider_one), Rc::clone(&divider_two)])
         .collect::<Vec<_>>();
 
@@ -57,100 +72,45 @@ fn solve_part_two(input: &str) -> usize {
     pos_div_one * pos_div_two
 }
 
-fn parse_pairs(input: &str) -> Vec<(Rc<PacketData>, Rc<PacketData>)> {
-    input
-        .split("\n\n")
-        .map(|str_pair| {
-            let split_pair = str_pair.split("\n").collect::<Vec<_>>();
-
-            let left = parse_packet_data(split_pair[0]);
-            let right = parse_packet_data(split_pair[1]);
-
-            (left, right)
+fn parse_pairs(input: &str) -> Result<Vec<PacketPair>, SolveError> {
+    input::blocks(input)
+        .map(|block| match block.lines() {
+            [left, right] => Ok((parse_packet_data(left)?, parse_packet_data(right)?)),
+            _ => Err(block.error("packets must come in pairs")),
         })
         .collect()
 }
 
-fn parse_packet_data(data_str: &str) -> Rc<PacketData> {
-    let tokens = parse_tokens(data_str.chars());
-    let (packet_data, _) = tokens_to_data(&tokens, None);
-    packet_data.expect(format!("could not parse tokens from {data_str}").as_str())
-}
-
-fn tokens_to_data(
-    tokens: &[Token],
-    packet_data: Option<Rc<PacketData>>,
-) -> (Option<Rc<PacketData>>, &[Token]) {
-    let split = tokens.split_first();
-    if split.is_none() {
-        return (packet_data, &[]);
+fn parse_packet_data(line: &Line) -> Result<Rc<PacketData>, SolveError> {
+    let mut cursor = line.cursor();
+    if cursor.peek() != Some('[') {
+        return Err(cursor.error("packet must be a list"));
     }
+    let packet_data = parse_element(&mut cursor)?;
+    cursor.end()?;
 
-    let t = split.unwrap().0;
-    let tail = split.unwrap().1;
-    if let Some(inner) = &packet_data {
-        match (t, inner.as_ref()) {
-            (Token::Comma, _) => return tokens_to_data(tail, packet_data),
-            (Token::Number(n), PacketData::List(l)) => {
-                let number = PacketData::new_number(n.parse().unwrap());
-                l.borrow_mut().push(number);
-                return tokens_to_data(tail, packet_data);
-            }
-            (Token::ListStart, PacketData::List(parent_to_be)) => {
-                let (child, new_tail) = tokens_to_data(tail, Some(PacketData::new_list(vec![])));
-                parent_to_be.borrow_mut().push(child.unwrap());
-                return tokens_to_data(new_tail, packet_data);
-            }
-            (Token::ListEnd, PacketData::List(_)) => {
-                return (packet_data, tail);
-            }
-            _ => panic!("unexpected token combination {t:?}"),
-        }
-    } else if t == &Token::ListStart && packet_data.is_none() {
-        return tokens_to_data(tail, Some(PacketData::new_list(vec![])));
-    }
-
-    panic!("unexpected token combination {t:?}")
+    Ok(packet_data)
 }
 
-fn parse_tokens(chars: Chars) -> Vec<Token> {
-    let raw_tokens = chars.map(|c| match c {
-        '[' => Token::ListStart,
-        ']' => Token::ListEnd,
-        ',' => Token::Comma,
-        n if n.is_numeric() => Token::Number(n.to_string()),
-        _ => panic!("unexpected character '{c}' encountered"),
-    });
-    let mut acc = vec![];
-
-    for current in raw_tokens {
-        let prev = acc.last();
-
-        match (current, prev) {
-            (Token::Number(c), Some(Token::Number(p))) => {
-                let t = Token::Number(p.clone() + &c);
-                let last_idx = acc.len() - 1;
-                acc[last_idx] = t;
-            }
-            (t, _) => {
-                acc.push(t);
-            }
-        }
+/// Number or list of elements, e.g. `[1,[2,3],[]]`.
+fn parse_element(cursor: &mut Cursor) -> Result<Rc<PacketData>, SolveError> {
+    if !cursor.eat("[") {
+        return Ok(PacketData::new_number(cursor.number()?));
+    }
+    if cursor.eat("]") {
+        return Ok(PacketData::new_list(vec![]));
     }
 
-    acc
-}
+    let elements = cursor.separated(",", parse_element)?;
+    cursor.expect("]")?;
 
-#[derive(Debug, Clone, PartialEq, Eq)]
-enum Token {
-    ListStart,
-    ListEnd,
-    Comma,
-    Number(String),
+    Ok(PacketData::new_list(elements))
 }
 
+pub type PacketPair = (Rc<PacketData>, Rc<PacketData>);
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum PacketData {
+pub enum PacketData {
     List(RefCell<Vec<Rc<PacketData>>>),
     Number(u64),
 }
@@ -184,14 +144,14 @@ impl PacketData {
                         return s;
                     }
 
-                    let decision = l.unwrap().is_sorted(&r.unwrap());
+                    let decision = l.unwrap().is_sorted(r.unwrap());
 
                     if decision != Sort::Undecided {
                         return decision;
                     }
                 }
 
-                return Sort::Undecided;
+                Sort::Undecided
             }
             (PacketData::List(_), PacketData::Number(n)) => self.is_sorted(&PacketData::List(
                 RefCell::new(vec![Rc::new(PacketData::Number(*n))]),
@@ -224,18 +184,12 @@ impl Display for PacketData {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             PacketData::List(l) => {
-                if let Err(e) = write!(f, "[") {
-                    return Err(e);
-                }
+                write!(f, "[")?;
                 for (i, e) in l.borrow().iter().enumerate() {
                     if i != 0 {
-                        if let Err(e) = write!(f, ",") {
-                            return Err(e);
-                        }
-                    }
-                    if let Err(e) = e.fmt(f) {
-                        return Err(e);
+                        write!(f, ",")?;
                     }
+                    e.fmt(f)?;
                 }
                 write!(f, "]")
             }
@@ -404,87 +358,34 @@ fn test_full() {
         "[1,[2,[3,[4,[5,6,0]]]],8,9]\n",
     );
 
-    let actual = solve_part_one(input);
+    let actual = solve_part_one(&parse_pairs(input).unwrap());
 
     assert_eq!(actual, 13);
 }
 
 #[test]
-fn test_parse_tokens() {
-    let chars = "[[8],[]]".chars();
-
-    let expected = vec![
-        Token::ListStart,
-        Token::ListStart,
-        Token::Number(String::from("8")),
-        Token::ListEnd,
-        Token::Comma,
-        Token::ListStart,
-        Token::ListEnd,
-        Token::ListEnd,
-    ];
-
-    let actual = parse_tokens(chars);
-
-    assert_eq!(actual, expected);
+fn test_parse_packet_data() {
+    for text in [
+        "[[8],[]]",
+        "[[3],[0,7,[[6,0,0,10],9,[],9,2]],[[10,3,7,[6]]]]",
+    ] {
+        let packet_data = parse_packet_data(&Line { index: 0, text }).unwrap();
+
+        assert_eq!(packet_data.to_string(), text);
+    }
 }
 
 #[test]
-fn test_parse_tokens_long() {
-    let chars = "[[3],[0,7,[[6,0,0,10],9,[],9,2]],[[10,3,7,[6]]]]".chars();
-
-    let expected = vec![
-        Token::ListStart,
-        Token::ListStart,
-        Token::Number(String::from("3")),
-        Token::ListEnd,
-        Token::Comma,
-        Token::ListStart,
-        Token::Number(String::from("0")),
-        Token::Comma,
-        Token::Number(String::from("7")),
-        Token::Comma,
-        Token::ListStart,
-        Token::ListStart,
-        Token::Number(String::from("6")),
-        Token::Comma,
-        Token::Number(String::from("0")),
-        Token::Comma,
-        Token::Number(String::from("0")),
-        Token::Comma,
-        Token::Number(String::from("10")),
-        Token::ListEnd,
-        Token::Comma,
-        Token::Number(String::from("9")),
-        Token::Comma,
-        Token::ListStart,
-        Token::ListEnd,
-        Token::Comma,
-        Token::Number(String::from("9")),
-        Token::Comma,
-        Token::Number(String::from("2")),
-        Token::ListEnd,
-        Token::ListEnd,
-        Token::Comma,
-        Token::ListStart,
-        Token::ListStart,
-        Token::Number(String::from("10")),
-        Token::Comma,
-        Token::Number(String::from("3")),
-        Token::Comma,
-        Token::Number(String::from("7")),
-        Token::Comma,
-        Token::ListStart,
-        Token::Number(String::from("6")),
-        Token::ListEnd,
-        Token::ListEnd,
-        Token::ListEnd,
-        Token::ListEnd,
-    ];
-
-    let actual = parse_tokens(chars);
-
-    assert_eq!(actual, expected);
+fn test_parse_packet_data_errors() {
+    let parse = |text| parse_packet_data(&Line { index: 0, text }).unwrap_err();
+
+    assert_eq!(
+        parse("[1,[2,x]]"),
+        SolveError::at(0, 6, "expected a number")
+    );
+    assert_eq!(parse("[1,[2]"), SolveError::at(0, 6, "expected ']'"));
+    assert_eq!(parse("[1]]"), SolveError::at(0, 3, "unexpected ']'"));
+    assert_eq!(parse("1"), SolveError::at(0, 0, "packet must be a list"));
 }
 
 #[test]
@@ -494,7 +395,7 @@ fn test_input() {
         "[[3],[0,7,[[6,0,0,10],9,[],9,2]],[[10,3,7,[6]]]]\n"
     );
 
-    let actual = solve_part_one(input);
+    let actual = solve_part_one(&parse_pairs(input).unwrap());
 
     assert_eq!(actual, 0);
 }
@@ -504,7 +405,42 @@ fn wrap_numbers<I>(nums: I) -> Vec<Rc<PacketData>>
 where
     I: IntoIterator<Item = u64>,
 {
-    nums.into_iter()
-        .map(|n| PacketData::new_number(n))
-        .collect()
+    nums.into_iter().map(PacketData::new_number).collect()
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "[1,1,3,1,1]\n",
+    "[1,1,5,1,1]\n",
+    "\n",
+    "[[1],[2,3,4]]\n",
+    "[[1],4]\n",
+    "\n",
+    "[9]\n",
+    "[[8,7,6]]\n",
+    "\n",
+    "[[4,4],4,4]\n",
+    "[[4,4],4,4,4]\n",
+    "\n",
+    "[7,7,7,7]\n",
+    "[7,7,7]\n",
+    "\n",
+    "[]\n",
+    "[3]\n",
+    "\n",
+    "[[[]]]\n",
+    "[[]]\n",
+    "\n",
+    "[1,[2,[3,[4,[5,6,7]]]],8,9]\n",
+    "[1,[2,[3,[4,[5,6,0]]]],8,9]\n",
+);
+
+aoc_common::examples! {
+    solution: Day13,
+    part_one: {
+        EXAMPLE => "13",
+    },
+    part_two: {
+        EXAMPLE => "140",
+    },
 }
@@ -1,26 +1,46 @@
-use std::{collections::HashSet, fs, path::PathBuf};
+use std::collections::HashSet;
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).unwrap();
+use aoc_common::{Answer, Direction, Point, Solution, SolveError};
 
-    let part_one = solve_part_one(&input);
-    let part_two = solve_part_two(&input);
+pub struct Day14;
 
-    return (part_one.to_string(), part_two.to_string());
-}
+aoc_common::register!(Day14);
+
+impl Solution for Day14 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 14;
+    const TITLE: &'static str = "Regolith Reservoir";
+
+    type Input = HashSet<Point<usize>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let rocks = parse_rocks(input)?;
+        if rocks.is_empty() {
+            return Err(SolveError::new("there has to be at least one rock"));
+        }
+
+        Ok(rocks)
+    }
 
-pub fn solve_part_one(input: &str) -> usize {
-    let rocks: HashSet<Point> = parse_rocks(&input);
+    fn part_one(&self, rocks: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(rocks).into())
+    }
+
+    fn part_two(&self, rocks: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(rocks).into())
+    }
+}
 
+fn solve_part_one(rocks: &HashSet<Point<usize>>) -> usize {
     let max_y = rocks.iter().max_by_key(|&p| p.y).unwrap().y;
 
     let sand_origin = Point { x: 500, y: 0 };
-    let mut sand: HashSet<Point> = HashSet::new();
+    let mut sand: HashSet<Point<usize>> = HashSet::new();
     loop {
         let mut sand_grain = sand_origin;
 
         while !sand.contains(&sand_grain) && sand_grain.y < max_y {
-            if let Some(next) = next_free_space(&sand_grain, &rocks, &sand, None) {
+            if let Some(next) = next_free_space(&sand_grain, rocks, &sand, None) {
                 sand_grain = next;
                 continue;
             }
@@ -33,22 +53,20 @@ pub fn solve_part_one(input: &str) -> usize {
         }
     }
 
-    return sand.len();
+    sand.len()
 }
 
-pub fn solve_part_two(input: &str) -> usize {
-    let rocks: HashSet<Point> = parse_rocks(&input);
-
+fn solve_part_two(rocks: &HashSet<Point<usize>>) -> usize {
     let max_y = rocks.iter().max_by_key(|&p| p.y).unwrap().y;
     let floor = max_y + 2;
 
     let sand_origin = Point { x: 500, y: 0 };
-    let mut sand: HashSet<Point> = HashSet::new();
+    let mut sand: HashSet<Point<usize>> = HashSet::new();
     loop {
         let mut sand_grain = sand_origin;
 
         while !sand.contains(&sand_grain) {
-            if let Some(next) = next_free_space(&sand_grain, &rocks, &sand, Some(floor)) {
+            if let Some(next) = next_free_space(&sand_grain, rocks, &sand, Some(floor)) {
                 sand_grain = next;
                 continue;
             }
@@ -61,16 +79,16 @@ pub fn solve_part_two(input: &str) -> usize {
         }
     }
 
-    return sand.len();
+    sand.len()
 }
 
 fn next_free_space(
-    current: &Point,
-    rocks: &HashSet<Point>,
-    sand: &HashSet<Point>,
+    current: &Point<usize>,
+    rocks: &HashSet<Point<usize>>,
+    sand: &HashSet<Point<usize>>,
     floor: Option<usize>,
-) -> Option<Point> {
-    let below = current.below();
+) -> Option<Point<usize>> {
+    let below = current.step(Direction::Down);
     if !rocks.contains(&below)
         && !sand.contains(&below)
         && (floor.is_none() || floor.unwrap() > below.y)
@@ -78,7 +96,7 @@ fn next_free_space(
         return Some(below);
     }
 
-    let left_down = current.left_down();
+    let left_down = below.step(Direction::Left);
     if !rocks.contains(&left_down)
         && !sand.contains(&left_down)
         && (floor.is_none() || floor.unwrap() > left_down.y)
@@ -86,7 +104,7 @@ fn next_free_space(
         return Some(left_down);
     }
 
-    let right_down = current.right_down();
+    let right_down = below.step(Direction::Right);
     if !rocks.contains(&right_down)
         && !sand.contains(&right_down)
         && (floor.is_none() || floor.unwrap() > right_down.y)
@@ -97,81 +115,58 @@ fn next_free_space(
     None
 }
 
-fn parse_rocks(input: &str) -> HashSet<Point> {
-    input
-        .lines()
-        .flat_map(|l| {
-            let pairs = l
-                .split(" -> ")
-                .filter_map(|s| {
-                    if s.is_empty() {
-                        return None;
-                    }
-
-                    let splits = s.split(",").collect::<Vec<_>>();
-
-                    Some(Point {
-                        x: splits[0].parse::<usize>().unwrap(),
-                        y: splits[1].parse::<usize>().unwrap(),
+fn parse_rocks(input: &str) -> Result<HashSet<Point<usize>>, SolveError> {
+    let mut rocks = HashSet::new();
+
+    for (i, l) in input.lines().enumerate() {
+        let pairs = l
+            .split(" -> ")
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                let coordinate = |c: Option<&str>| {
+                    c.and_then(|c| c.parse::<usize>().ok()).ok_or_else(|| {
+                        SolveError::at_token(i, l, s, format!("invalid point '{s}'"))
                     })
+                };
+                let mut splits = s.split(",");
+
+                Ok(Point {
+                    x: coordinate(splits.next())?,
+                    y: coordinate(splits.next())?,
                 })
-                .collect::<Vec<_>>();
-
-            let mut formations = vec![];
-            for p in pairs.windows(2) {
-                formations.push(p[0]);
-                formations.push(p[1]);
-                match p {
-                    [l, r] if l.x == r.x => {
-                        let max_y = std::cmp::max(l.y, r.y);
-                        let min_y = std::cmp::min(l.y, r.y);
-                        (min_y..=max_y).for_each(|y| {
-                            formations.push(Point { x: l.x, y });
-                        });
-                    }
-                    [l, r] if l.y == r.y => {
-                        let max_x = std::cmp::max(l.x, r.x);
-                        let min_x = std::cmp::min(l.x, r.x);
-                        (min_x..=max_x).for_each(|x| {
-                            formations.push(Point { x, y: l.y });
-                        });
-                    }
-                    _ => panic!("unexpected window {p:?}"),
+            })
+            .collect::<Result<Vec<_>, SolveError>>()?;
+
+        for p in pairs.windows(2) {
+            rocks.insert(p[0]);
+            rocks.insert(p[1]);
+            match p {
+                [l, r] if l.x == r.x => {
+                    let max_y = std::cmp::max(l.y, r.y);
+                    let min_y = std::cmp::min(l.y, r.y);
+                    (min_y..=max_y).for_each(|y| {
+                        rocks.insert(Point { x: l.x, y });
+                    });
+                }
+                [l, r] if l.y == r.y => {
+                    let max_x = std::cmp::max(l.x, r.x);
+                    let min_x = std::cmp::min(l.x, r.x);
+                    (min_x..=max_x).for_each(|x| {
+                        rocks.insert(Point { x, y: l.y });
+                    });
+                }
+                _ => {
+                    return Err(SolveError::at(
+                        i,
+                        0,
+                        format!("rock path is not a straight line {p:?}"),
+                    ))
                 }
             }
-
-            formations
-        })
-        .collect()
-}
-
-#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
-struct Point {
-    x: usize,
-    y: usize,
-}
-
-impl Point {
-    pub fn below(&self) -> Point {
-        Point {
-            x: self.x,
-            y: self.y + 1,
         }
     }
 
-    pub fn left_down(&self) -> Point {
-        Point {
-            x: self.x - 1,
-            y: self.y + 1,
-        }
-    }
-
-    pub fn right_down(&self) -> Point {
-        Point {
-            x: self.x + 1,
-            y: self.y + 1,
-        }
-    }
+    Ok(rocks)
 }
 
 #[test]
@@ -204,7 +199,7 @@ fn test_rock_formation_parsing() {
         "503,4 -> 502,4 -> 502,9 -> 494,9\n"
     );
 
-    let actual = parse_rocks(&input);
+    let actual = parse_rocks(input).unwrap();
 
     assert_eq!(
         actual.len(),
@@ -220,3 +215,19 @@ fn test_rock_formation_parsing() {
         );
     }
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "498,4 -> 498,6 -> 496,6\n",
+    "503,4 -> 502,4 -> 502,9 -> 494,9\n",
+);
+
+aoc_common::examples! {
+    solution: Day14,
+    part_one: {
+        EXAMPLE => "24",
+    },
+    part_two: {
+        EXAMPLE => "93",
+    },
+}
@@ -1,20 +1,73 @@
-use std::{fs, hash::Hash, path::PathBuf};
-
+use aoc_common::{Answer, Param, Params, Point, Solution, SolveError};
 use regex::Regex;
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).unwrap();
-
-    // Sensor at x=1363026, y=2928920: closest beacon is at x=1571469, y=3023534
-    let part_one = solve_part_one(&input);
-    let part_two = solve_part_two(&input);
+pub struct Day15 {
+    /// Row in which the positions that cannot contain a beacon are counted.
+    pub row: i64,
+    /// Largest x and y coordinate the distress beacon can be at.
+    pub max_coordinate: i64,
+}
 
-    (part_one.to_string(), part_two.to_string())
+impl Day15 {
+    pub const PUZZLE: Day15 = Day15 {
+        row: 2_000_000,
+        max_coordinate: 4_000_000,
+    };
 }
 
-fn solve_part_one(input: &str) -> usize {
-    let pairs = parse_pairs(&input);
+aoc_common::register!(Day15::PUZZLE);
+
+impl Solution for Day15 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 15;
+    const TITLE: &'static str = "Beacon Exclusion Zone";
+
+    type Input = Vec<Pair>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        // Sensor at x=1363026, y=2928920: closest beacon is at x=1571469, y=3023534
+        let pairs = parse_pairs(input)?;
+        if pairs.is_empty() {
+            return Err(SolveError::new("there has to be at least one sensor"));
+        }
+
+        Ok(pairs)
+    }
+
+    fn part_one(&self, pairs: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(pairs, self.row).into())
+    }
+
+    fn part_two(&self, pairs: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part_two(pairs, self.max_coordinate)
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::new("no position for the distress beacon found"))
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param {
+                name: "row",
+                value: self.row,
+                description: "row in which the positions without a beacon are counted",
+            },
+            Param {
+                name: "max_coordinate",
+                value: self.max_coordinate,
+                description: "largest x and y coordinate of the distress beacon",
+            },
+        ]
+    }
+
+    fn with_params(&self, params: &Params) -> Result<Self, SolveError> {
+        Ok(Day15 {
+            row: params.get("row", self.row)?,
+            max_coordinate: params.get("max_coordinate", self.max_coordinate)?,
+        })
+    }
+}
 
+fn solve_part_one(pairs: &[Pair], y: i64) -> usize {
     let max_distance = pairs.iter().map(|p| p.distance).max().unwrap();
 
     let min_x = pairs
@@ -22,26 +75,24 @@ fn solve_part_one(input: &str) -> usize {
         .flat_map(|p| [p.beacon.x, p.sensor.x])
         .min()
         .unwrap()
-        - max_distance as i64;
+        - max_distance;
     let max_x = pairs
         .iter()
         .flat_map(|p| [p.beacon.x, p.sensor.x])
         .max()
         .unwrap()
-        + max_distance as i64;
+        + max_distance;
 
-    let y = 2_000_000;
     (min_x..max_x)
         .filter(|&x| pairs.iter().any(|p| p.is_in_range(&Point { x, y })))
         .count()
 }
 
-fn solve_part_two(input: &str) -> usize {
-    let pairs = parse_pairs(&input);
+fn solve_part_two(pairs: &[Pair], max_coordinate: i64) -> Option<usize> {
     let search_min = Point { x: 0, y: 0 };
     let search_max = Point {
-        x: 4_000_000,
-        y: 4_000_000,
+        x: max_coordinate,
+        y: max_coordinate,
     };
 
     let result = pairs
@@ -50,22 +101,21 @@ fn solve_part_two(input: &str) -> usize {
         .find(|point| {
             pairs
                 .iter()
-                .all(|p| p.sensor.distance_to(&point) > p.distance)
-        })
-        .unwrap();
+                .all(|p| p.sensor.manhattan_distance(*point) > p.distance)
+        })?;
 
-    return (result.x * 4_000_000 + result.y) as usize;
+    Some((result.x * 4_000_000 + result.y) as usize)
 }
 
 fn perimeter_points(
-    origin: &Point,
-    radius: u64,
-    (search_min, search_max): (&Point, &Point),
-) -> Vec<Point> {
-    (origin.y..origin.y + radius as i64)
+    origin: &Point<i64>,
+    radius: i64,
+    (search_min, search_max): (&Point<i64>, &Point<i64>),
+) -> Vec<Point<i64>> {
+    (origin.y..origin.y + radius)
         .rev()
         .enumerate()
-        .chain((origin.y - radius as i64..origin.y).enumerate())
+        .chain((origin.y - radius..origin.y).enumerate())
         .flat_map(|(x, y)| {
             if x == 0 {
                 vec![Point { x: origin.x, y }]
@@ -88,40 +138,54 @@ fn perimeter_points(
         .collect()
 }
 
-fn parse_pairs(input: &str) -> Vec<Pair> {
+fn parse_pairs(input: &str) -> Result<Vec<Pair>, SolveError> {
     let line_regex =
         Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$")
             .unwrap();
 
     input
         .lines()
-        .filter_map(|l| {
-            line_regex.captures(l).and_then(|c| {
-                Some(Pair::new(
-                    Point {
-                        x: c[1].parse().unwrap(),
-                        y: c[2].parse().unwrap(),
-                    },
-                    Point {
-                        x: c[3].parse().unwrap(),
-                        y: c[4].parse().unwrap(),
-                    },
-                ))
-            })
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, l)| {
+            let c = line_regex.captures(l).ok_or_else(|| {
+                SolveError::at(
+                    i,
+                    0,
+                    "expected 'Sensor at x=.., y=..: closest beacon is at x=.., y=..'",
+                )
+            })?;
+            let coordinate = |n: usize| {
+                let m = c.get(n).expect("all groups have to match");
+                m.as_str()
+                    .parse()
+                    .map_err(|_| SolveError::at(i, m.start(), "coordinate is out of range"))
+            };
+
+            Ok(Pair::new(
+                Point {
+                    x: coordinate(1)?,
+                    y: coordinate(2)?,
+                },
+                Point {
+                    x: coordinate(3)?,
+                    y: coordinate(4)?,
+                },
+            ))
         })
-        .collect::<Vec<_>>()
+        .collect()
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-struct Pair {
-    sensor: Point,
-    beacon: Point,
-    distance: u64,
+pub struct Pair {
+    sensor: Point<i64>,
+    beacon: Point<i64>,
+    distance: i64,
 }
 
 impl Pair {
-    pub fn new(sensor: Point, beacon: Point) -> Self {
-        let distance = sensor.distance_to(&beacon);
+    pub fn new(sensor: Point<i64>, beacon: Point<i64>) -> Self {
+        let distance = sensor.manhattan_distance(beacon);
 
         Self {
             sensor,
@@ -130,21 +194,38 @@ impl Pair {
         }
     }
 
-    pub fn is_in_range(&self, other: &Point) -> bool {
-        &self.beacon != other && self.sensor.distance_to(other) <= self.distance
+    pub fn is_in_range(&self, other: &Point<i64>) -> bool {
+        &self.beacon != other && self.sensor.manhattan_distance(*other) <= self.distance
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-struct Point {
-    x: i64,
-    y: i64,
-}
-
-impl Point {
-    pub fn distance_to(&self, other: &Point) -> u64 {
-        ((self.x - other.x).abs() + (self.y - other.y).abs())
-            .try_into()
-            .unwrap()
-    }
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "Sensor at x=2, y=18: closest beacon is at x=-2, y=15\n",
+    "Sensor at x=9, y=16: closest beacon is at x=10, y=16\n",
+    "Sensor at x=13, y=2: closest beacon is at x=15, y=3\n",
+    "Sensor at x=12, y=14: closest beacon is at x=10, y=16\n",
+    "Sensor at x=10, y=20: closest beacon is at x=10, y=16\n",
+    "Sensor at x=14, y=17: closest beacon is at x=10, y=16\n",
+    "Sensor at x=8, y=7: closest beacon is at x=2, y=10\n",
+    "Sensor at x=2, y=0: closest beacon is at x=2, y=10\n",
+    "Sensor at x=0, y=11: closest beacon is at x=2, y=10\n",
+    "Sensor at x=20, y=14: closest beacon is at x=25, y=17\n",
+    "Sensor at x=17, y=20: closest beacon is at x=21, y=22\n",
+    "Sensor at x=16, y=7: closest beacon is at x=15, y=3\n",
+    "Sensor at x=14, y=3: closest beacon is at x=15, y=3\n",
+    "Sensor at x=20, y=1: closest beacon is at x=15, y=3\n",
+);
+
+aoc_common::examples! {
+    solution: Day15 {
+        row: 10,
+        max_coordinate: 20,
+    },
+    part_one: {
+        EXAMPLE => "26",
+    },
+    part_two: {
+        EXAMPLE => "56000011",
+    },
 }
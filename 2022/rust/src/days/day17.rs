@@ -1,41 +1,81 @@
-use std::{collections::HashSet, fs, hash::Hash, path::PathBuf};
+use std::collections::HashSet;
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).unwrap();
-    let directions = parse_directions(&input);
-    let part_one = solve_part_one(&directions);
-    let part_two = solve_part_two(&directions);
+use aoc_common::{Answer, Direction, Param, Params, Point, Solution, SolveError};
 
-    (part_one.to_string(), part_two.to_string())
+pub struct Day17 {
+    /// Number of rocks whose tower is measured in part one.
+    pub rocks: usize,
 }
 
-fn parse_directions(input: &str) -> Vec<Direction> {
-    input
-        .chars()
-        .map_while(|c| match c {
-            '<' => Some(Direction::Left),
-            '>' => Some(Direction::Right),
-            _ => None,
+impl Day17 {
+    pub const PUZZLE: Day17 = Day17 { rocks: 2022 };
+}
+
+aoc_common::register!(Day17::PUZZLE);
+
+impl Solution for Day17 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 17;
+    const TITLE: &'static str = "Pyroclastic Flow";
+
+    type Input = Vec<Direction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_directions(input)
+    }
+
+    fn part_one(&self, directions: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(directions, self.rocks).into())
+    }
+
+    fn part_two(&self, _directions: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::NotImplemented)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![Param {
+            name: "rocks",
+            value: self.rocks as i64,
+            description: "rocks that fall in part one",
+        }]
+    }
+
+    fn with_params(&self, params: &Params) -> Result<Self, SolveError> {
+        Ok(Day17 {
+            rocks: params.get("rocks", self.rocks)?,
         })
-        .collect()
+    }
 }
 
-fn solve_part_one(directions: &[Direction]) -> u64 {
-    let heights_per_round = run_simulation(directions, 2022);
+fn parse_directions(input: &str) -> Result<Vec<Direction>, SolveError> {
+    let directions = input
+        .trim_end()
+        .chars()
+        .enumerate()
+        .map(|(i, c)| match c {
+            '<' => Ok(Direction::Left),
+            '>' => Ok(Direction::Right),
+            _ => Err(SolveError::at(
+                0,
+                i,
+                format!("unexpected jet direction '{c}'"),
+            )),
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if directions.is_empty() {
+        return Err(SolveError::new(
+            "there has to be at least one jet direction",
+        ));
+    }
 
-    *heights_per_round.last().unwrap()
+    Ok(directions)
 }
 
-fn solve_part_two(directions: &[Direction]) -> u64 {
-    let num_of_directions = directions.len() as u64;
-    let heights_per_round = run_simulation(directions, num_of_directions as usize);
-    let max = *heights_per_round.last().unwrap();
-    let num_of_rocks: u64 = 2022;
-    let max_multiplicand = num_of_rocks % num_of_directions;
-    let height_without_rest = max * max_multiplicand;
-    //println!("{num_of_directions} {max} {height_without_rest} {}", heights_per_round[22]);
-    //println!("{heights_per_round:#?}");
-    0
+fn solve_part_one(directions: &[Direction], rocks: usize) -> u64 {
+    let heights_per_round = run_simulation(directions, rocks);
+
+    heights_per_round.last().copied().unwrap_or(0)
 }
 
 fn run_simulation(directions: &[Direction], num_of_rocks: usize) -> Vec<u64> {
@@ -48,27 +88,20 @@ fn run_simulation(directions: &[Direction], num_of_rocks: usize) -> Vec<u64> {
     let mut count: usize = 0;
 
     let mut height_per_round: Vec<u64> = Vec::with_capacity(num_of_rocks);
-    let mut rocks: HashSet<Point> = HashSet::new();
+    let mut rocks: HashSet<Point<i64>> = HashSet::new();
     for n in 0..num_of_rocks {
         let shape = shape_for_index(n);
         let mut vertices = shape.vertices();
         vertices = move_x(left_distance, &vertices);
-        let offset_y = rocks
-            .iter()
-            .max_by_key(|&p| p.y)
-            .and_then(|p| Some(p.y))
-            .unwrap_or(-1)
-            + bottom_distance;
+        let offset_y =
+            rocks.iter().max_by_key(|&p| p.y).map(|p| p.y).unwrap_or(-1) + bottom_distance;
         // println!("offset {offset_y}");
         vertices = move_y(offset_y, &vertices);
 
         // println!("{vertices:?}");
 
         loop {
-            let push_x = match &directions[count % direction_count] {
-                Direction::Left => -1,
-                Direction::Right => 1,
-            };
+            let push_x = directions[count % direction_count].offset().x;
 
             // println!("{push_x:?}");
 
@@ -107,11 +140,11 @@ fn run_simulation(directions: &[Direction], num_of_rocks: usize) -> Vec<u64> {
     height_per_round
 }
 
-fn move_y(y: i64, vertices: &Vec<Point>) -> Vec<Point> {
+fn move_y(y: i64, vertices: &[Point<i64>]) -> Vec<Point<i64>> {
     vertices.iter().map(|p| Point::new(p.x, p.y + y)).collect()
 }
 
-fn move_x(x: i64, vertices: &Vec<Point>) -> Vec<Point> {
+fn move_x(x: i64, vertices: &[Point<i64>]) -> Vec<Point<i64>> {
     vertices.iter().map(|p| Point::new(p.x + x, p.y)).collect()
 }
 
@@ -126,29 +159,6 @@ fn shape_for_index(index: usize) -> Shape {
     }
 }
 
-fn print_board(rocks: &HashSet<Point>) {
-    let max_y = rocks.iter().max_by_key(|&p| p.y).unwrap().y;
-    println!();
-    for y in (0..=max_y).rev() {
-        print!("|");
-        for x in 0..7 {
-            if rocks.contains(&Point::new(x, y)) {
-                print!("#");
-            } else {
-                print!(".")
-            }
-        }
-        println!("|");
-    }
-    println!("+-------+")
-}
-
-#[derive(Debug, PartialEq, Eq)]
-enum Direction {
-    Left,
-    Right,
-}
-
 #[derive(Debug, PartialEq, Eq)]
 enum Shape {
     Line = 0,
@@ -158,20 +168,8 @@ enum Shape {
     Square = 4,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-struct Point {
-    x: i64,
-    y: i64,
-}
-
-impl Point {
-    pub fn new(x: i64, y: i64) -> Self {
-        Point { x, y }
-    }
-}
-
 impl Shape {
-    pub fn vertices(&self) -> Vec<Point> {
+    pub fn vertices(&self) -> Vec<Point<i64>> {
         match self {
             Shape::Line => vec![
                 Point::new(0, 0),
@@ -208,3 +206,10 @@ impl Shape {
         }
     }
 }
+
+aoc_common::examples! {
+    solution: Day17::PUZZLE,
+    part_one: {
+        ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>" => "3068",
+    },
+}
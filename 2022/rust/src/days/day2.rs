@@ -0,0 +1,77 @@
+use aoc_common::{Answer, Solution, SolveError};
+
+pub struct Day02;
+
+aoc_common::register!(Day02);
+
+impl Solution for Day02 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Rock Paper Scissors";
+
+    /// Rounds of the strategy guide as the index of both columns, e.g. `(0, 2)` for `A Z`.
+    type Input = Vec<(u32, u32)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty())
+            .map(|(i, l)| match l.as_bytes() {
+                [opponent @ b'A'..=b'C', b' ', second @ b'X'..=b'Z'] => {
+                    Ok(((opponent - b'A') as u32, (second - b'X') as u32))
+                }
+                [b'A'..=b'C', b' ', ..] => Err(SolveError::at(i, 2, "expected X, Y or Z")),
+                _ => Err(SolveError::at(
+                    i,
+                    0,
+                    "expected A, B or C followed by X, Y or Z",
+                )),
+            })
+            .collect()
+    }
+
+    fn part_one(&self, rounds: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(rounds).into())
+    }
+
+    fn part_two(&self, rounds: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(rounds).into())
+    }
+}
+
+/// The second column is the shape to play.
+fn solve_part_one(rounds: &[(u32, u32)]) -> u32 {
+    rounds
+        .iter()
+        .map(|&(opponent, shape)| round_score(opponent, shape))
+        .sum()
+}
+
+/// The second column is how the round has to end, 0 for a loss, 1 for a draw and 2 for a win.
+fn solve_part_two(rounds: &[(u32, u32)]) -> u32 {
+    rounds
+        .iter()
+        .map(|&(opponent, outcome)| round_score(opponent, (opponent + outcome + 2) % 3))
+        .sum()
+}
+
+/// Score of playing `shape` against `opponent`, where 0 is rock, 1 paper and 2 scissors.
+fn round_score(opponent: u32, shape: u32) -> u32 {
+    let outcome = (shape + 4 - opponent) % 3;
+
+    shape + 1 + outcome * 3
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!("A Y\n", "B X\n", "C Z\n");
+
+aoc_common::examples! {
+    solution: Day02,
+    part_one: {
+        EXAMPLE => "15",
+    },
+    part_two: {
+        EXAMPLE => "12",
+    },
+}
@@ -1,32 +1,60 @@
-use std::{collections::HashSet, fs, path::PathBuf};
+use std::collections::HashSet;
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("should have been able to read the file");
-    let lines: Vec<&str> = input.lines().filter(|l| !l.trim().is_empty()).collect();
+use aoc_common::{Answer, Solution, SolveError};
 
-    let part_one = solve_part_one(&lines);
-    let part_two = solve_part_two(&lines);
+pub struct Day03;
 
-    (part_one.to_string(), part_two.to_string())
+aoc_common::register!(Day03);
+
+impl Solution for Day03 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Rucksack Reorganization";
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty())
+            .map(|(i, l)| match l.find(|c: char| !c.is_ascii_alphabetic()) {
+                Some(column) => Err(SolveError::at(i, column, "items must be letters")),
+                None if l.len() % 2 != 0 => Err(SolveError::at(
+                    i,
+                    0,
+                    "compartments must contain the same number of items",
+                )),
+                None => Ok(String::from(l)),
+            })
+            .collect()
+    }
+
+    fn part_one(&self, lines: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part_one(lines).map(Answer::from)
+    }
+
+    fn part_two(&self, lines: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part_two(lines).map(Answer::from)
+    }
 }
 
-fn solve_part_one(lines: &Vec<&str>) -> u32 {
-    lines.iter().fold(0, |acc, l| {
+fn solve_part_one(lines: &[String]) -> Result<u32, SolveError> {
+    lines.iter().try_fold(0, |acc, l| {
         let middle = l.len() / 2;
         let first: HashSet<char> = HashSet::from_iter(l[0..middle].chars());
         let second: HashSet<char> = HashSet::from_iter(l[middle..].chars());
         let in_both = first
             .intersection(&second)
-            .nth(0)
-            .expect("there has to be an intersection");
+            .next()
+            .ok_or_else(|| SolveError::new(format!("no item is in both compartments of '{l}'")))?;
 
-        acc + item_priority(in_both)
+        Ok(acc + item_priority(in_both))
     })
 }
 
-fn solve_part_two(lines: &Vec<&str>) -> u32 {
+fn solve_part_two(lines: &[String]) -> Result<u32, SolveError> {
     lines
-        .as_slice()
         .chunks(3)
         .map(|group| {
             group
@@ -35,10 +63,12 @@ fn solve_part_two(lines: &Vec<&str>) -> u32 {
                     let set: HashSet<char> = HashSet::from_iter(s.chars());
                     set
                 })
-                .reduce(|accum, item| HashSet::<char>::from_iter(accum.intersection(&item).map(|c| *c)))
-                .and_then(|intersection| intersection.into_iter().nth(0))
-                .and_then(|badge| Some(item_priority(&badge)))
-                .expect("no badge could be found")
+                .reduce(|accum, item| {
+                    HashSet::<char>::from_iter(accum.intersection(&item).copied())
+                })
+                .and_then(|intersection| intersection.into_iter().next())
+                .map(|badge| item_priority(&badge))
+                .ok_or_else(|| SolveError::new("no badge could be found"))
         })
         .sum()
 }
@@ -49,3 +79,23 @@ fn item_priority(item: &char) -> u32 {
         i => i - 38,
     }
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "vJrwpWtwJgWrhcsFMMfFFhFp\n",
+    "jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\n",
+    "PmmdzqPrVvPwwTWBwg\n",
+    "wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn\n",
+    "ttgJtRGJQctTZtZT\n",
+    "CrZsJsPPZsGzwwsLwLmpwMDw\n",
+);
+
+aoc_common::examples! {
+    solution: Day03,
+    part_one: {
+        EXAMPLE => "157",
+    },
+    part_two: {
+        EXAMPLE => "70",
+    },
+}
@@ -0,0 +1,88 @@
+use std::ops::RangeInclusive;
+
+use aoc_common::{Answer, Solution, SolveError};
+
+pub struct Day04;
+
+aoc_common::register!(Day04);
+
+impl Solution for Day04 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Camp Cleanup";
+
+    type Input = Vec<(RangeInclusive<u32>, RangeInclusive<u32>)>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty())
+            .map(|(i, l)| {
+                let (first, second) = l
+                    .split_once(',')
+                    .ok_or_else(|| SolveError::at(i, 0, "expected two comma separated ranges"))?;
+
+                Ok((parse_range(i, l, first)?, parse_range(i, l, second)?))
+            })
+            .collect()
+    }
+
+    fn part_one(&self, pairs: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(pairs).into())
+    }
+
+    fn part_two(&self, pairs: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(pairs).into())
+    }
+}
+
+fn parse_range(i: usize, line: &str, range: &str) -> Result<RangeInclusive<u32>, SolveError> {
+    let section = |s: &str| {
+        s.parse::<u32>()
+            .map_err(|_| SolveError::at_token(i, line, s, format!("invalid section '{s}'")))
+    };
+    let (lower, upper) = range
+        .split_once('-')
+        .ok_or_else(|| SolveError::at_token(i, line, range, "expected a range like 2-4"))?;
+
+    Ok(section(lower)?..=section(upper)?)
+}
+
+fn solve_part_one(pairs: &[(RangeInclusive<u32>, RangeInclusive<u32>)]) -> usize {
+    pairs
+        .iter()
+        .filter(|(a, b)| fully_contains(a, b) || fully_contains(b, a))
+        .count()
+}
+
+fn solve_part_two(pairs: &[(RangeInclusive<u32>, RangeInclusive<u32>)]) -> usize {
+    pairs
+        .iter()
+        .filter(|(a, b)| a.start() <= b.end() && b.start() <= a.end())
+        .count()
+}
+
+fn fully_contains(a: &RangeInclusive<u32>, b: &RangeInclusive<u32>) -> bool {
+    a.start() <= b.start() && b.end() <= a.end()
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "2-4,6-8\n",
+    "2-3,4-5\n",
+    "5-7,7-9\n",
+    "2-8,3-7\n",
+    "6-6,4-6\n",
+    "2-6,4-8\n",
+);
+
+aoc_common::examples! {
+    solution: Day04,
+    part_one: {
+        EXAMPLE => "2",
+    },
+    part_two: {
+        EXAMPLE => "4",
+    },
+}
@@ -1,7 +1,8 @@
-use std::{
-    collections::{VecDeque},
-    fs,
-    path::PathBuf,
+use std::collections::VecDeque;
+
+use aoc_common::{
+    input::{self, Block},
+    Answer, Solution, SolveError,
 };
 
 /*
@@ -113,42 +114,79 @@ In this example, the CrateMover 9001 has put the crates in a totally different o
 Before the rearrangement process finishes, update your simulation so that the Elves know where they should stand to be ready to unload the final supplies. After the rearrangement procedure completes, what crate ends up on top of each stack?
 */
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("should have been able to read the file");
-    let instructions: Vec<CraneInstruction> = parse_instructions(&input);
+pub struct Day05;
+
+aoc_common::register!(Day05);
+
+impl Solution for Day05 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Supply Stacks";
+
+    type Input = Drawing;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let mut blocks = input::blocks(input);
+        let (Some(stacks), Some(procedure)) = (blocks.next(), blocks.next()) else {
+            return Err(SolveError::new(
+                "the stacks must be followed by a blank line and the rearrangement procedure",
+            ));
+        };
+
+        let stacks = parse_stacks(&stacks)?;
+        let instructions = procedure
+            .lines()
+            .iter()
+            .map(|line| parse_instruction_line(line.index, line.text, stacks.len()))
+            .collect::<Result<_, _>>()?;
 
-    let part_one = solve_part_one(&mut build_stacks(&input), &instructions);
-    let part_two = solve_part_two(&mut build_stacks(&input), &instructions);
+        Ok(Drawing {
+            stacks,
+            instructions,
+        })
+    }
+
+    fn part_one(&self, drawing: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part_one(&mut drawing.stacks.clone(), &drawing.instructions).map(Answer::from)
+    }
+
+    fn part_two(&self, drawing: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part_two(&mut drawing.stacks.clone(), &drawing.instructions).map(Answer::from)
+    }
+}
 
-    return (part_one, part_two);
+pub struct Drawing {
+    /// Crates of every stack, the top crate is at the front.
+    stacks: Vec<VecDeque<char>>,
+    instructions: Vec<CraneInstruction>,
 }
 
 fn solve_part_one(
-    stacks: &mut [VecDeque<char>; 9],
+    stacks: &mut [VecDeque<char>],
     instructions: &Vec<CraneInstruction>,
-) -> String {
+) -> Result<String, SolveError> {
     for instruction in instructions {
         for _ in 0..instruction.count {
             let item = stacks[instruction.from - 1]
                 .pop_front()
-                .expect("element is expected");
+                .ok_or_else(|| instruction.empty_stack_error())?;
             stacks[instruction.to - 1].push_front(item);
         }
     }
 
-    stacks.iter().map(|s| s.front()).filter_map(|s| s).collect()
+    Ok(stacks.iter().filter_map(|s| s.front()).collect())
 }
 
 fn solve_part_two(
-    stacks: &mut [VecDeque<char>; 9],
+    stacks: &mut [VecDeque<char>],
     instructions: &Vec<CraneInstruction>,
-) -> String {
+) -> Result<String, SolveError> {
     for instruction in instructions {
         let mut items: Vec<char> = Vec::new();
         for _ in 0..instruction.count {
             let item = stacks[instruction.from - 1]
                 .pop_front()
-                .expect("element is expected");
+                .ok_or_else(|| instruction.empty_stack_error())?;
             items.push(item);
         }
         items
@@ -157,47 +195,111 @@ fn solve_part_two(
             .for_each(|item| stacks[instruction.to - 1].push_front(*item));
     }
 
-    stacks.iter().map(|s| s.front()).filter_map(|s| s).collect()
+    Ok(stacks.iter().filter_map(|s| s.front()).collect())
 }
 
-fn build_stacks(input: &str) -> [VecDeque<char>; 9] {
-    let mut stacks: [VecDeque<char>; 9] = Default::default();
-
-    for line in input.lines().take(8) {
-        line.chars()
-            .collect::<Vec<char>>()
-            .chunks(4)
-            .map(|chunk| chunk.iter().filter(|c| c.is_alphabetic()).nth(0))
-            .enumerate()
-            .for_each(|(i, item)| match item {
-                Some(c) => stacks[i].push_back(c.to_owned()),
-                None => (),
-            });
+/// Parses the drawing of the stacks, whose last line numbers the stacks.
+fn parse_stacks(drawing: &Block) -> Result<Vec<VecDeque<char>>, SolveError> {
+    let (numbers, crates) = drawing
+        .lines()
+        .split_last()
+        .expect("a block has at least one line");
+    for (i, number) in numbers.text.split_whitespace().enumerate() {
+        if number.parse() != Ok(i + 1) {
+            return Err(numbers.error_at_token(number, format!("expected stack {}", i + 1)));
+        }
+    }
+    let mut stacks = vec![VecDeque::new(); numbers.text.split_whitespace().count()];
+
+    for line in crates {
+        for (column, c) in line.text.char_indices() {
+            if column % 4 != 1 || c == ' ' {
+                continue;
+            }
+            if !c.is_ascii_alphabetic() {
+                return Err(line.error_at(column, "crates must be marked with a letter"));
+            }
+            let stack = stacks
+                .get_mut(column / 4)
+                .ok_or_else(|| line.error_at(column, "there is no stack below the crate"))?;
+            stack.push_back(c);
+        }
     }
 
-    stacks
-}
-
-fn parse_instructions(input: &str) -> Vec<CraneInstruction> {
-    input
-        .lines()
-        .skip(10)
-        .map(|l| parse_instruction_line(l))
-        .collect()
+    Ok(stacks)
 }
 
-fn parse_instruction_line(line: &str) -> CraneInstruction {
+fn parse_instruction_line(
+    line_index: usize,
+    line: &str,
+    stack_count: usize,
+) -> Result<CraneInstruction, SolveError> {
     let words = line.split(" ").collect::<Vec<_>>();
-    let count = words[1].parse().expect("must be able to parse count");
-    let from = words[3].parse().expect("must be able to parse from");
-    let to = words[5].parse().expect("must be able to parse to");
+    if words.len() != 6 {
+        return Err(SolveError::at(
+            line_index,
+            0,
+            "instruction must look like 'move N from A to B'",
+        ));
+    }
 
-    CraneInstruction { count, from, to }
+    let number = |word: &str, range: std::ops::RangeInclusive<usize>| {
+        word.parse()
+            .ok()
+            .filter(|n| range.contains(n))
+            .ok_or_else(|| {
+                SolveError::at_token(line_index, line, word, format!("invalid number '{word}'"))
+            })
+    };
+    let count = number(words[1], 0..=usize::MAX)?;
+    let from = number(words[3], 1..=stack_count)?;
+    let to = number(words[5], 1..=stack_count)?;
+
+    Ok(CraneInstruction {
+        line_index,
+        count,
+        from,
+        to,
+    })
 }
 
 #[derive(Debug)]
 struct CraneInstruction {
+    line_index: usize,
     count: usize,
     from: usize,
     to: usize,
 }
+
+impl CraneInstruction {
+    fn empty_stack_error(&self) -> SolveError {
+        SolveError::at(
+            self.line_index,
+            0,
+            format!("stack {} has no crates left to move", self.from),
+        )
+    }
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "    [D]    \n",
+    "[N] [C]    \n",
+    "[Z] [M] [P]\n",
+    " 1   2   3 \n",
+    "\n",
+    "move 1 from 2 to 1\n",
+    "move 3 from 1 to 3\n",
+    "move 2 from 2 to 1\n",
+    "move 1 from 1 to 2\n",
+);
+
+aoc_common::examples! {
+    solution: Day05,
+    part_one: {
+        EXAMPLE => "CMZ",
+    },
+    part_two: {
+        EXAMPLE => "MCD",
+    },
+}
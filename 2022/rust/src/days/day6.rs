@@ -1,4 +1,4 @@
-use std::{fs, path::PathBuf};
+use aoc_common::{Answer, Solution, SolveError};
 
 /*
 --- Day 6: Tuning Trouble ---
@@ -51,28 +51,44 @@ Here are the first positions of start-of-message markers for all of the above ex
 How many characters need to be processed before the first start-of-message marker is detected?
 */
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("file must be readable");
+pub struct Day06;
 
-    let datastream = input.lines().nth(0).expect("file must have one line");
+aoc_common::register!(Day06);
 
-    let chars: Vec<char> = datastream.chars().collect();
+impl Solution for Day06 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Tuning Trouble";
 
-    let part_one = solve_part_one(&chars);
-    let part_two = solve_part_two(&chars);
+    type Input = Vec<char>;
 
-    return (part_one.to_string(), part_two.to_string());
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let datastream = input
+            .lines()
+            .next()
+            .ok_or_else(|| SolveError::new("file must have one line"))?;
+
+        Ok(datastream.chars().collect())
+    }
+
+    fn part_one(&self, chars: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part_one(chars).map(Answer::from)
+    }
+
+    fn part_two(&self, chars: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part_two(chars).map(Answer::from)
+    }
 }
 
-fn solve_part_one(chars: &Vec<char>) -> usize {
-    find_marker(chars, 4).expect("marker must be found")
+fn solve_part_one(chars: &[char]) -> Result<usize, SolveError> {
+    find_marker(chars, 4).ok_or_else(|| SolveError::new("no start-of-packet marker found"))
 }
 
-fn solve_part_two(chars: &Vec<char>) -> usize {
-    find_marker(chars, 14).expect("marker must be found")
+fn solve_part_two(chars: &[char]) -> Result<usize, SolveError> {
+    find_marker(chars, 14).ok_or_else(|| SolveError::new("no start-of-message marker found"))
 }
 
-fn find_marker(chars: &Vec<char>, number_of_distinct: usize) -> Option<usize> {
+fn find_marker(chars: &[char], number_of_distinct: usize) -> Option<usize> {
     let one_less_than_distinct = number_of_distinct - 1;
     for (i, _c) in chars.iter().enumerate().skip(one_less_than_distinct) {
         let range = &chars[i - one_less_than_distinct..=i];
@@ -87,3 +103,21 @@ fn find_marker(chars: &Vec<char>, number_of_distinct: usize) -> Option<usize> {
 
     None
 }
+
+aoc_common::examples! {
+    solution: Day06,
+    part_one: {
+        "mjqjpqmgbljsphdztnvjfqwrcgsmlb" => "7",
+        "bvwbjplbgvbhsrlpgdmjqwftvncz" => "5",
+        "nppdvjthqldpwncqszvftbrmjlhg" => "6",
+        "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg" => "10",
+        "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw" => "11",
+    },
+    part_two: {
+        "mjqjpqmgbljsphdztnvjfqwrcgsmlb" => "19",
+        "bvwbjplbgvbhsrlpgdmjqwftvncz" => "23",
+        "nppdvjthqldpwncqszvftbrmjlhg" => "23",
+        "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg" => "29",
+        "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw" => "26",
+    },
+}
@@ -1,9 +1,6 @@
-use std::{
-    cell::{RefCell},
-    fmt, fs,
-    path::PathBuf,
-    rc::Rc,
-};
+use std::{cell::RefCell, fmt, rc::Rc};
+
+use aoc_common::{Answer, Param, Params, Solution, SolveError};
 
 /*
 --- Day 7: No Space Left On Device ---
@@ -107,35 +104,86 @@ Directories e and a are both too small; deleting them would not free up enough s
 Find the smallest directory that, if deleted, would free up enough space on the filesystem to run the update. What is the total size of that directory?
 */
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("file must be readable");
-
-    let lines = input
-    .lines()
-    .skip(1)
-    .filter(|l| !l.trim().is_empty())
-    .filter_map(|l| parse_line(l));
+pub struct Day07 {
+    /// Size of the disk of the device.
+    pub total_space: usize,
+    /// Unused space the update needs.
+    pub space_needed: usize,
+}
 
-    let mut file_system = FileSystem::new();
+impl Day07 {
+    pub const PUZZLE: Day07 = Day07 {
+        total_space: 70_000_000,
+        space_needed: 30_000_000,
+    };
+}
 
-    for line in lines {
-        match line {
-            Line::Command(Command::ChangeDirectory(p)) => file_system.change_directory(p),
-            Line::Command(Command::List) => (),
-            Line::Directory(name) => {
-                let new_dir = Directory::new(name, Rc::clone(&file_system.current_directory));
-                file_system.add_directory_to_current(new_dir)
+aoc_common::register!(Day07::PUZZLE);
+
+impl Solution for Day07 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "No Space Left On Device";
+
+    type Input = Vec<usize>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let lines = input
+            .lines()
+            .enumerate()
+            .skip(1)
+            .filter(|(_, l)| !l.trim().is_empty());
+
+        let mut file_system = FileSystem::new();
+
+        for (i, l) in lines {
+            match parse_line(i, l)? {
+                Line::Command(Command::ChangeDirectory(p)) => file_system
+                    .change_directory(p)
+                    .map_err(|e| SolveError::at(i, 0, e))?,
+                Line::Command(Command::List) => (),
+                Line::Directory(name) => {
+                    let new_dir = Directory::new(name, Rc::clone(&file_system.current_directory));
+                    file_system.add_directory_to_current(new_dir)
+                }
+                Line::File(name, size) => file_system.add_file_to_current(File::new(name, size)),
             }
-            Line::File(name, size) => file_system.add_file_to_current(File::new(name, size)),
         }
+
+        let dir_sizes = file_system.get_root().borrow().all_dir_sizes();
+
+        Ok(dir_sizes)
     }
 
-    let dir_sizes = file_system.get_root().borrow().all_dir_sizes();
+    fn part_one(&self, dir_sizes: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(dir_sizes).into())
+    }
 
-    let part_one = solve_part_one(&dir_sizes);
-    let part_two = solve_part_two(&dir_sizes);
+    fn part_two(&self, dir_sizes: &Self::Input) -> Result<Answer, SolveError> {
+        solve_part_two(dir_sizes, self.total_space, self.space_needed).map(Answer::from)
+    }
+
+    fn params(&self) -> Vec<Param> {
+        vec![
+            Param {
+                name: "total_space",
+                value: self.total_space as i64,
+                description: "size of the disk",
+            },
+            Param {
+                name: "space_needed",
+                value: self.space_needed as i64,
+                description: "unused space the update needs",
+            },
+        ]
+    }
 
-    (part_one.to_string(), part_two.to_string())
+    fn with_params(&self, params: &Params) -> Result<Self, SolveError> {
+        Ok(Day07 {
+            total_space: params.get("total_space", self.total_space)?,
+            space_needed: params.get("space_needed", self.space_needed)?,
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -157,48 +205,51 @@ enum Line {
     File(String, usize),
 }
 
-fn solve_part_one(dir_sizes: &Vec<usize>) -> usize {
+fn solve_part_one(dir_sizes: &[usize]) -> usize {
     dir_sizes.iter().filter(|&&s| s <= 100_000).sum()
 }
 
-fn solve_part_two(dir_sizes: &Vec<usize>) -> usize {
-    let total_space: usize = 70_000_000;
-    let space_needed: usize = 30_000_000;
-    let max_space_used: usize = *dir_sizes.iter().max().expect("element expected");
-    let space_available: usize = total_space - max_space_used;
+fn solve_part_two(
+    dir_sizes: &[usize],
+    total_space: usize,
+    space_needed: usize,
+) -> Result<usize, SolveError> {
+    let max_space_used: usize = *dir_sizes
+        .iter()
+        .max()
+        .ok_or_else(|| SolveError::new("there has to be a directory"))?;
+    let space_available: usize = total_space.saturating_sub(max_space_used);
 
-    let smallest_possible = *dir_sizes
+    dir_sizes
         .iter()
         .filter(|&&s| (s + space_available) >= space_needed)
         .min()
-        .expect("element expected");
-
-    smallest_possible
+        .copied()
+        .ok_or_else(|| SolveError::new("no directory frees up enough space"))
 }
-fn parse_line(line: &str) -> Option<Line> {
+
+fn parse_line(line_index: usize, line: &str) -> Result<Line, SolveError> {
     if line == "$ ls" {
-        return Some(Line::Command(Command::List));
+        return Ok(Line::Command(Command::List));
     }
 
     let parts: Vec<&str> = line.split(" ").collect();
 
-    if parts[0] == "$" {
-        let param = match parts[2] {
-            ".." => ChangeDirectoryParameter::Previous,
-            s => ChangeDirectoryParameter::Next(s.to_string()),
-        };
-
-        return Some(Line::Command(Command::ChangeDirectory(param)));
+    match parts[..] {
+        ["$", "cd", ".."] => Ok(Line::Command(Command::ChangeDirectory(
+            ChangeDirectoryParameter::Previous,
+        ))),
+        ["$", "cd", name] => Ok(Line::Command(Command::ChangeDirectory(
+            ChangeDirectoryParameter::Next(name.to_string()),
+        ))),
+        ["$", ..] => Err(SolveError::at(line_index, 0, "unknown command")),
+        ["dir", name] => Ok(Line::Directory(name.to_string())),
+        [size, name] => size
+            .parse::<usize>()
+            .map(|s| Line::File(name.to_string(), s))
+            .map_err(|_| SolveError::at(line_index, 0, format!("invalid file size '{size}'"))),
+        _ => Err(SolveError::at(line_index, 0, "unexpected line")),
     }
-
-    if parts[0] == "dir" {
-        return Some(Line::Directory(parts[1].to_string()));
-    }
-
-    parts[0]
-        .parse::<usize>()
-        .and_then(|s| Ok(Line::File(parts[1].to_string(), s)))
-        .ok()
 }
 
 #[derive(Debug)]
@@ -217,14 +268,14 @@ impl FileSystem {
         }
     }
 
-    fn change_directory(&mut self, param: ChangeDirectoryParameter) {
+    fn change_directory(&mut self, param: ChangeDirectoryParameter) -> Result<(), String> {
         self.current_directory = match param {
             ChangeDirectoryParameter::Previous => {
                 let previous = self
                     .current_directory
                     .borrow()
                     .get_parent()
-                    .expect("arrived at the root");
+                    .ok_or("cannot leave the root directory")?;
                 Rc::clone(&previous)
             }
             ChangeDirectoryParameter::Next(name) => {
@@ -233,11 +284,13 @@ impl FileSystem {
                     .directories
                     .iter()
                     .find(|d| d.borrow().name == name)
-                    .expect("directory does not exist");
+                    .ok_or_else(|| format!("directory '{name}' does not exist"))?;
 
-                Rc::clone(&dir)
+                Rc::clone(dir)
             }
-        }
+        };
+
+        Ok(())
     }
 
     fn add_directory_to_current(&mut self, directory: Directory) {
@@ -331,12 +384,7 @@ impl Directory {
             .directories
             .iter()
             .flat_map(|d| d.borrow().all_dir_sizes());
-        self.directories
-            .iter()
-            .map(|d| d.borrow().get_size())
-            .chain(below)
-            .chain(vec![self.get_size()])
-            .collect()
+        below.chain(vec![self.get_size()]).collect()
     }
 }
 
@@ -355,7 +403,7 @@ impl HoldsDirectories for Directory {
 impl InDirectory for Directory {
     fn get_parent(&self) -> Option<Rc<RefCell<Directory>>> {
         match &self.parent {
-            Some(p) => Some(Rc::clone(&p)),
+            Some(p) => Some(Rc::clone(p)),
             None => None,
         }
     }
@@ -373,3 +421,40 @@ impl Size for Directory {
         files_size + dir_size
     }
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "$ cd /\n",
+    "$ ls\n",
+    "dir a\n",
+    "14848514 b.txt\n",
+    "8504156 c.dat\n",
+    "dir d\n",
+    "$ cd a\n",
+    "$ ls\n",
+    "dir e\n",
+    "29116 f\n",
+    "2557 g\n",
+    "62596 h.lst\n",
+    "$ cd e\n",
+    "$ ls\n",
+    "584 i\n",
+    "$ cd ..\n",
+    "$ cd ..\n",
+    "$ cd d\n",
+    "$ ls\n",
+    "4060174 j\n",
+    "8033020 d.log\n",
+    "5626152 d.ext\n",
+    "7214296 k\n",
+);
+
+aoc_common::examples! {
+    solution: Day07::PUZZLE,
+    part_one: {
+        EXAMPLE => "95437",
+    },
+    part_two: {
+        EXAMPLE => "24933642",
+    },
+}
@@ -1,92 +1,64 @@
-use std::{fs, path::PathBuf};
+use aoc_common::{Answer, Grid, Point, Solution, SolveError};
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("file must be readable");
+pub struct Day08;
 
-    let tree_heights = input
-        .lines()
-        .map(|l| {
-            l.chars()
-                .map(|c| {
-                    c.to_digit(10)
-                        .expect(format!("cannot parse {c} as number in line {l}").as_str())
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-
-    let part_one = solve_part_one(&tree_heights);
-    let part_two = solve_part_two(&tree_heights);
-
-    (part_one.to_string(), part_two.to_string())
-}
-
-fn solve_part_one(tree_heights: &Vec<Vec<u32>>) -> usize {
-    // collection[y][x]
-
-    let mut visible_trees = Vec::new();
+aoc_common::register!(Day08);
 
-    for (y, row) in tree_heights.iter().enumerate() {
-        for (x, &tree) in row.iter().enumerate() {
-            // trees on the outside are always visible
-            if y == 0 || y == tree_heights.len() - 1 || x == 0 || x == row.len() - 1 {
-                visible_trees.push((y, x));
-                continue;
-            }
+impl Solution for Day08 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Treetop Tree House";
 
-            let left_invisible = row[..x].iter().any(|&t| t >= tree);
-            if !left_invisible {
-                visible_trees.push((y, x));
-                continue;
-            }
+    type Input = Grid<u32>;
 
-            let right_invisible = row[x + 1..].iter().any(|&t| t >= tree);
-            if !right_invisible {
-                visible_trees.push((y, x));
-                continue;
-            }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Grid::parse(input, |c| {
+            c.to_digit(10)
+                .ok_or_else(|| format!("cannot parse {c} as number"))
+        })
+    }
 
-            let top_invisible = tree_heights[..y].iter().map(|r| r[x]).any(|t| t >= tree);
-            if !top_invisible {
-                visible_trees.push((y, x));
-                continue;
-            }
+    fn part_one(&self, tree_heights: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_one(tree_heights).into())
+    }
 
-            let bottom_invisible = tree_heights[y + 1..]
-                .iter()
-                .map(|r| r[x])
-                .any(|t| t >= tree);
-            if !bottom_invisible {
-                visible_trees.push((y, x));
-                continue;
-            }
-        }
+    fn part_two(&self, tree_heights: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_part_two(tree_heights).into())
     }
+}
 
-    visible_trees.len()
+fn solve_part_one(tree_heights: &Grid<u32>) -> usize {
+    tree_heights
+        .iter()
+        .filter(|&(Point { x, y }, &tree)| {
+            let row = tree_heights.row(y);
+            let column = tree_heights.column(x);
+
+            // trees on the outside are always visible as nothing blocks the view
+            row[..x].iter().all(|&t| t < tree)
+                || row[x + 1..].iter().all(|&t| t < tree)
+                || column.clone().take(y).all(|&t| t < tree)
+                || column.skip(y + 1).all(|&t| t < tree)
+        })
+        .count()
 }
 
-fn solve_part_two(tree_heights: &Vec<Vec<u32>>) -> usize {
-    let mut viewing_scores = Vec::new();
+fn solve_part_two(tree_heights: &Grid<u32>) -> usize {
+    tree_heights
+        .iter()
+        .map(|(Point { x, y }, &tree)| {
+            let row = tree_heights.row(y);
+            let column = tree_heights.column(x);
 
-    for (y, row) in tree_heights.iter().enumerate() {
-        for (x, &tree) in row.iter().enumerate() {
             let left_view = calculate_viewing_distance(row[..x].iter().rev(), tree);
             let right_view = calculate_viewing_distance(row[x + 1..].iter(), tree);
-            let top_view =
-                calculate_viewing_distance(tree_heights[..y].iter().rev().map(|r| &r[x]), tree);
-            let bottom_view =
-                calculate_viewing_distance(tree_heights[y + 1..].iter().map(|r| &r[x]), tree);
+            let top_view = calculate_viewing_distance(column.clone().take(y).rev(), tree);
+            let bottom_view = calculate_viewing_distance(column.skip(y + 1), tree);
 
-            let viewing_score = left_view * right_view * top_view * bottom_view;
-            viewing_scores.push(viewing_score);
-        }
-    }
-
-    *viewing_scores
-        .iter()
+            left_view * right_view * top_view * bottom_view
+        })
         .max()
-        .expect("at least one item expected")
+        .expect("grid is never empty")
 }
 
 fn calculate_viewing_distance<'a, I>(trees: I, tree: u32) -> usize
@@ -101,10 +73,23 @@ where
         .enumerate()
         .find_map(|(i, &t)| {
             if t < tree && i < num_trees - 1 {
-                return None;
+                None
             } else {
                 Some(i + 1)
             }
         })
         .unwrap_or(0)
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!("30373\n", "25512\n", "65332\n", "33549\n", "35390\n");
+
+aoc_common::examples! {
+    solution: Day08,
+    part_one: {
+        EXAMPLE => "21",
+    },
+    part_two: {
+        EXAMPLE => "8",
+    },
+}
@@ -1,4 +1,6 @@
-use std::{collections::HashSet, fs, hash::Hash, iter, path::PathBuf, str::Lines};
+use std::{collections::HashSet, str::Lines};
+
+use aoc_common::{Answer, Direction, Point, Solution, SolveError};
 
 /*
 --- Day 9: Rope Bridge ---
@@ -685,38 +687,44 @@ Now, the tail (9) visits 36 positions (including s) at least once:
 Simulate your complete series of motions on a larger rope with ten knots. How many positions does the tail of the rope visit at least once?
 */
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("file must be readable");
-    let instructions = parse_instructions(input.lines());
+pub struct Day09;
 
-    let part_one = run_simulation(&instructions, 1);
-    let part_two = run_simulation(&instructions, 9);
+aoc_common::register!(Day09);
 
-    (part_one.to_string(), part_two.to_string())
-}
+impl Solution for Day09 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Rope Bridge";
+
+    type Input = Vec<Direction>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_instructions(input.lines())
+    }
+
+    fn part_one(&self, instructions: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(run_simulation(instructions, 1).into())
+    }
 
-#[derive(Eq, Hash, PartialEq, Clone, Copy, Debug)]
-struct Position {
-    x: i32,
-    y: i32,
+    fn part_two(&self, instructions: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(run_simulation(instructions, 9).into())
+    }
 }
 
-fn run_simulation(instructions: &Vec<Instruction>, tail_length: usize) -> usize {
-    let mut head = Position { x: 0, y: 0 };
-    let mut tails: Vec<Position> = iter::repeat(Position { x: 0, y: 0 })
-        .take(tail_length)
-        .collect();
+fn run_simulation(instructions: &[Direction], tail_length: usize) -> usize {
+    let mut head = Point::origin();
+    let mut tails: Vec<Point<i32>> = std::iter::repeat_n(Point::origin(), tail_length).collect();
 
-    let mut visited: Vec<HashSet<Position>> = tails.iter().map(|&p| HashSet::from([p])).collect();
+    let mut visited: Vec<HashSet<Point<i32>>> = tails.iter().map(|&p| HashSet::from([p])).collect();
 
-    for instruction in instructions {
-        head = move_head(&head, instruction);
-        tails[0] = move_tail(&head, &tails[0]);
+    for &instruction in instructions {
+        head = head.step(instruction);
+        tails[0] = move_tail(head, tails[0]);
         visited[0].insert(tails[0]);
 
         for i in 1..tail_length {
             let previous_tail = tails[i - 1];
-            tails[i] = move_tail(&previous_tail, &tails[i]);
+            tails[i] = move_tail(previous_tail, tails[i]);
             visited[i].insert(tails[i]);
         }
     }
@@ -724,138 +732,54 @@ fn run_simulation(instructions: &Vec<Instruction>, tail_length: usize) -> usize
     visited.last().unwrap().len()
 }
 
-fn move_head(&head: &Position, instruction: &Instruction) -> Position {
-    match instruction {
-        Instruction::Up => move_up(&head),
-        Instruction::Down => move_down(&head),
-        Instruction::Left => move_left(&head),
-        Instruction::Right => move_right(&head),
-    }
-}
-
-fn move_tail(&head: &Position, &tail: &Position) -> Position {
-    if are_touching(&head, &tail) {
+fn move_tail(head: Point<i32>, tail: Point<i32>) -> Point<i32> {
+    // head and tail are touching, also diagonally
+    if head.chebyshev_distance(tail) <= 1 {
         return tail;
     }
 
-    if head.x == tail.x {
-        return Position {
-            y: if head.y > tail.y {
-                tail.y + 1
-            } else {
-                tail.y - 1
-            },
-            ..tail
-        };
-    }
-
-    if head.y == tail.y {
-        return Position {
-            x: if head.x > tail.x {
-                tail.x + 1
-            } else {
-                tail.x - 1
-            },
-            ..tail
-        };
-    }
-
-    let diff_x = tail.x.abs_diff(head.x);
-    let diff_y = tail.y.abs_diff(head.y);
-
-    match (diff_x, diff_y) {
-        (2, 2) => Position {
-            x: if head.x == tail.x + 2 {
-                head.x - 1
-            } else {
-                head.x + 1
-            },
-            y: if head.y == tail.y + 2 {
-                head.y - 1
-            } else {
-                head.y + 1
-            },
-        },
-        (1, _) => Position {
-            x: head.x,
-            y: if head.y == tail.y + 2 {
-                head.y - 1
-            } else {
-                head.y + 1
-            },
-        },
-        (_, 1) => Position {
-            y: head.y,
-            x: if head.x == tail.x + 2 {
-                head.x - 1
-            } else {
-                head.x + 1
-            },
-        },
-        _ => panic!("unexpected move dx {diff_x} dy {diff_y}"),
-    }
+    let difference = head - tail;
+    tail + Point::new(difference.x.signum(), difference.y.signum())
 }
 
-fn are_touching(a: &Position, b: &Position) -> bool {
-    let same = a == b;
-    let row_touch = a.y == b.y && a.x.abs_diff(b.x) == 1;
-    let col_touch = a.x == b.x && a.y.abs_diff(b.y) == 1;
-    let dia_touch = a.x.abs_diff(b.x) == 1 && a.y.abs_diff(b.y) == 1;
-
-    same || row_touch || col_touch || dia_touch
-}
+fn parse_instructions(lines: Lines) -> Result<Vec<Direction>, SolveError> {
+    let mut instructions = Vec::new();
+
+    for (i, l) in lines.enumerate() {
+        let steps = l
+            .get(2..)
+            .and_then(|s| s.parse::<usize>().ok())
+            .ok_or_else(|| SolveError::at(i, 2, "cannot parse steps"))?;
+
+        let instr = match l.chars().next() {
+            Some('U') => Direction::Up,
+            Some('D') => Direction::Down,
+            Some('L') => Direction::Left,
+            Some('R') => Direction::Right,
+            _ => return Err(SolveError::at(i, 0, "unknown instruction")),
+        };
 
-fn move_up(&position: &Position) -> Position {
-    Position {
-        y: position.y + 1,
-        ..position
+        instructions.extend(std::iter::repeat_n(instr, steps));
     }
-}
-fn move_down(&position: &Position) -> Position {
-    Position {
-        y: position.y - 1,
-        ..position
-    }
-}
-fn move_right(&position: &Position) -> Position {
-    Position {
-        x: position.x + 1,
-        ..position
-    }
-}
-fn move_left(&position: &Position) -> Position {
-    Position {
-        x: position.x - 1,
-        ..position
-    }
-}
 
-fn parse_instructions(lines: Lines) -> Vec<Instruction> {
-    lines
-        .flat_map(|l| {
-            let steps = l[2..].parse::<usize>().expect("cannot parse steps");
-
-            let instr = l
-                .chars()
-                .next()
-                .and_then(|c| match c {
-                    'U' => Some(Instruction::Up),
-                    'D' => Some(Instruction::Down),
-                    'L' => Some(Instruction::Left),
-                    'R' => Some(Instruction::Right),
-                    _ => None,
-                })
-                .expect("unknown instruction");
-
-            iter::repeat(instr).take(steps)
-        })
-        .collect()
+    Ok(instructions)
 }
 
-#[derive(Debug, Clone, Copy)]
-enum Instruction {
-    Up,
-    Down,
-    Left,
-    Right,
+#[cfg(test)]
+const EXAMPLE: &str =
+    concat!("R 4\n", "U 4\n", "L 3\n", "D 1\n", "R 4\n", "D 1\n", "L 5\n", "R 2\n");
+
+#[cfg(test)]
+const LARGER_EXAMPLE: &str =
+    concat!("R 5\n", "U 8\n", "L 8\n", "D 3\n", "R 17\n", "D 10\n", "L 25\n", "U 20\n");
+
+aoc_common::examples! {
+    solution: Day09,
+    part_one: {
+        EXAMPLE => "13",
+    },
+    part_two: {
+        EXAMPLE => "1",
+        LARGER_EXAMPLE => "36",
+    },
 }
@@ -1,52 +1,69 @@
-use std::{fs, path::PathBuf};
+use aoc_common::{Answer, Solution, SolveError};
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+pub struct Day01;
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+aoc_common::register!(Day01);
 
-    (first.to_string(), second.to_string())
+impl Solution for Day01 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Trebuchet?!";
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input
+            .lines()
+            .filter(|l| !l.trim().is_empty())
+            .map(String::from)
+            .collect())
+    }
+
+    fn part_one(&self, lines: &Self::Input) -> Result<Answer, SolveError> {
+        solve_first(lines).map(Answer::from)
+    }
+
+    fn part_two(&self, lines: &Self::Input) -> Result<Answer, SolveError> {
+        solve_second(lines).map(Answer::from)
+    }
 }
 
-fn solve_first(input: &str) -> i32 {
-    let sum: i32 = input
-        .lines()
-        .filter(|l| !l.trim().is_empty())
+fn solve_first(lines: &[String]) -> Result<i32, SolveError> {
+    lines
+        .iter()
         .map(|l| {
+            let no_number = || SolveError::new(format!("there must be a number in '{l}'"));
             let first_number = l
                 .chars()
-                .find(|c| c.is_numeric())
-                .expect("there must be a number");
+                .find(|c| c.is_ascii_digit())
+                .ok_or_else(no_number)?;
             let last_number = l
                 .chars()
                 .rev()
-                .find(|c| c.is_numeric())
-                .expect("there must be a last number");
+                .find(|c| c.is_ascii_digit())
+                .ok_or_else(no_number)?;
 
             let number = format!("{}{}", first_number, last_number)
                 .parse::<i32>()
                 .expect("must be a number");
 
-            number
+            Ok(number)
         })
-        .sum();
-    sum
+        .sum()
 }
 
-fn solve_second(input: &str) -> i32 {
+fn solve_second(lines: &[String]) -> Result<i32, SolveError> {
     let number_strings = vec![
         "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
     ];
 
-    let sum: i32 = input
-        .lines()
-        .filter(|l| !l.trim().is_empty())
+    lines
+        .iter()
         .map(|l| {
             let number_indices: Vec<(usize, String)> = l
                 .chars()
                 .enumerate()
-                .filter(|(_, c)| c.is_numeric())
+                .filter(|(_, c)| c.is_ascii_digit())
                 .map(|(i, c)| (i, c.to_string()))
                 .chain(number_strings.iter().enumerate().flat_map(|(i, e)| {
                     l.match_indices(e)
@@ -54,24 +71,47 @@ fn solve_second(input: &str) -> i32 {
                 }))
                 .collect();
 
+            let no_number = || SolveError::new(format!("there must be a number in '{l}'"));
             let first = number_indices
                 .iter()
                 .min_by_key(|(i, _)| i)
-                .and_then(|(_, n)| Some(n))
-                .expect("there must be a first number");
+                .map(|(_, n)| n)
+                .ok_or_else(no_number)?;
             let last = number_indices
                 .iter()
                 .max_by_key(|(i, _)| i)
-                .and_then(|(_, n)| Some(n))
-                .expect("there must be a last number");
+                .map(|(_, n)| n)
+                .ok_or_else(no_number)?;
 
             let number = format!("{}{}", first, last)
                 .parse::<i32>()
                 .expect("must be a number");
 
-            number
+            Ok(number)
         })
-        .sum();
+        .sum()
+}
+
+#[cfg(test)]
+const EXAMPLE_ONE: &str = concat!("1abc2\n", "pqr3stu8vwx\n", "a1b2c3d4e5f\n", "treb7uchet\n");
+
+#[cfg(test)]
+const EXAMPLE_TWO: &str = concat!(
+    "two1nine\n",
+    "eightwothree\n",
+    "abcone2threexyz\n",
+    "xtwone3four\n",
+    "4nineeightseven2\n",
+    "zoneight234\n",
+    "7pqrstsixteen\n",
+);
 
-    sum
+aoc_common::examples! {
+    solution: Day01,
+    part_one: {
+        EXAMPLE_ONE => "142",
+    },
+    part_two: {
+        EXAMPLE_TWO => "281",
+    },
 }
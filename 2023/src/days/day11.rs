@@ -1,67 +1,50 @@
-use std::{collections::HashSet, fs, path::PathBuf};
+use std::collections::HashSet;
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+use aoc_common::{Answer, Grid, Point, Solution, SolveError};
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+pub struct Day11;
 
-    (first.to_string(), second.to_string())
-}
+aoc_common::register!(Day11);
 
-fn solve_first(input: &str) -> i64 {
-    let universe: Vec<Vec<Observation>> = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| match c {
-                    '#' => Observation::Galaxy,
-                    '.' => Observation::Void,
-                    _ => unreachable!("only . and # expected"),
-                })
-                .collect::<Vec<Observation>>()
-        })
-        .collect();
+impl Solution for Day11 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 11;
+    const TITLE: &'static str = "Cosmic Expansion";
 
-    let mut expanded_universe: Vec<Vec<Observation>> = universe
-        .into_iter()
-        .flat_map(|row| {
-            if row.iter().all(|o| *o == Observation::Void) {
-                vec![row.clone(), row.clone()]
-            } else {
-                vec![row]
-            }
-        })
-        .collect();
+    type Input = Grid<Observation>;
 
-    expanded_universe = transpose(expanded_universe)
-        .into_iter()
-        .flat_map(|row| {
-            if row.iter().all(|o| *o == Observation::Void) {
-                vec![row.clone(), row.clone()]
-            } else {
-                vec![row]
-            }
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Grid::parse(input, |c| match c {
+            '#' => Ok(Observation::Galaxy),
+            '.' => Ok(Observation::Void),
+            _ => Err("only . and # expected".to_string()),
         })
-        .collect();
+    }
 
-    expanded_universe = transpose(expanded_universe);
+    fn part_one(&self, universe: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(universe).into())
+    }
 
-    let all_galaxies: Vec<Point> = expanded_universe
+    fn part_two(&self, _universe: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::NotImplemented)
+    }
+}
+
+fn solve_first(universe: &Grid<Observation>) -> i64 {
+    let expanded_universe = expand_rows(&expand_rows(universe).transpose()).transpose();
+
+    let all_galaxies: Vec<Point<i64>> = expanded_universe
         .iter()
-        .enumerate()
-        .flat_map(|(y, row)| {
-            row.iter().enumerate().filter_map(move |(x, o)| match o {
-                Observation::Void => None,
-                Observation::Galaxy => Some(Point {
-                    x: x.try_into().unwrap(),
-                    y: y.try_into().unwrap(),
-                }),
-            })
+        .filter_map(|(Point { x, y }, o)| match o {
+            Observation::Void => None,
+            Observation::Galaxy => Some(Point {
+                x: x.try_into().unwrap(),
+                y: y.try_into().unwrap(),
+            }),
         })
         .collect();
 
-    let galaxy_pairs: HashSet<(&Point, &Point)> = all_galaxies
+    let galaxy_pairs: HashSet<(&Point<i64>, &Point<i64>)> = all_galaxies
         .iter()
         .enumerate()
         .flat_map(|(i, a)| {
@@ -73,39 +56,51 @@ fn solve_first(input: &str) -> i64 {
 
     let sum_distances = galaxy_pairs
         .iter()
-        .map(|(a, b)| taxicab_distance(a, b))
+        .map(|(a, b)| a.manhattan_distance(**b))
         .sum();
 
     sum_distances
 }
 
-fn taxicab_distance(a: &Point, b: &Point) -> i64 {
-    let d = (a.x - b.x).abs() + (a.y - b.y).abs();
-
-    d
-}
-
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash, PartialOrd, Ord)]
-struct Point {
-    x: i64,
-    y: i64,
-}
+/// Universe where every row without a galaxy appears twice.
+fn expand_rows(universe: &Grid<Observation>) -> Grid<Observation> {
+    let rows = universe
+        .rows()
+        .flat_map(|row| {
+            if row.iter().all(|o| *o == Observation::Void) {
+                vec![row.to_vec(), row.to_vec()]
+            } else {
+                vec![row.to_vec()]
+            }
+        })
+        .collect();
 
-fn transpose<T>(v: Vec<Vec<T>>) -> Vec<Vec<T>>
-where
-    T: Clone,
-{
-    (0..v[0].len())
-        .map(|i| v.iter().map(|inner| inner[i].clone()).collect::<Vec<T>>())
-        .collect()
+    Grid::from_rows(rows).expect("rows have the same length as before")
 }
 
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
-enum Observation {
+pub enum Observation {
     Void,
     Galaxy,
 }
 
-fn solve_second(input: &str) -> usize {
-    0
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "...#......\n",
+    ".......#..\n",
+    "#.........\n",
+    "..........\n",
+    "......#...\n",
+    ".#........\n",
+    ".........#\n",
+    "..........\n",
+    ".......#..\n",
+    "#...#.....\n",
+);
+
+aoc_common::examples! {
+    solution: Day11,
+    part_one: {
+        EXAMPLE => "374",
+    },
 }
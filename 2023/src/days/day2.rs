@@ -1,16 +1,30 @@
-use std::{fs, path::PathBuf};
+use aoc_common::{Answer, Solution, SolveError};
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+pub struct Day02;
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
-    (first.to_string(), second.to_string())
-}
+aoc_common::register!(Day02);
+
+impl Solution for Day02 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Cube Conundrum";
+
+    type Input = Vec<Game>;
 
-fn solve_first(input: &str) -> i32 {
-    let games = parse_games(input);
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_games(input)
+    }
+
+    fn part_one(&self, games: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(games).into())
+    }
+
+    fn part_two(&self, games: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_second(games).into())
+    }
+}
 
+fn solve_first(games: &[Game]) -> i32 {
     let max_red = 12;
     let max_green = 13;
     let max_blue = 14;
@@ -27,9 +41,7 @@ fn solve_first(input: &str) -> i32 {
     })
 }
 
-fn solve_second(input: &str) -> i32 {
-    let games = parse_games(input);
-
+fn solve_second(games: &[Game]) -> i32 {
     games.iter().fold(0, |acc, g| {
         let max_red = g.rounds.iter().max_by_key(|r| r.red).unwrap().red;
         let max_green = g.rounds.iter().max_by_key(|r| r.green).unwrap().green;
@@ -39,44 +51,73 @@ fn solve_second(input: &str) -> i32 {
     })
 }
 
-fn parse_games(input: &str) -> Vec<Game> {
+fn parse_games(input: &str) -> Result<Vec<Game>, SolveError> {
     input
         .lines()
-        .filter(|l| !l.trim().is_empty())
-        .map(|l| {
-            let s = l[5..].to_owned();
-            let (id, game_str) = s.split_once(':').expect("must contain colon");
-            let game_id: i32 = id.parse().expect("id must be a number");
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, l)| {
+            let s = l
+                .strip_prefix("Game ")
+                .ok_or_else(|| SolveError::at(i, 0, "must start with 'Game '"))?;
+            let (id, game_str) = s
+                .split_once(':')
+                .ok_or_else(|| SolveError::at(i, 0, "must contain colon"))?;
+            let game_id: i32 = id
+                .parse()
+                .map_err(|_| SolveError::at_token(i, l, id, "id must be a number"))?;
             let rounds = game_str
                 .split(';')
                 .map(|rs| {
-                    let (r, g, b) = rs.split(',').fold((0, 0, 0), |(r, g, b), c| {
-                        match c.trim().split(' ').collect::<Vec<_>>()[..] {
-                            [n, "red"] => (n.parse().unwrap(), g, b),
-                            [n, "green"] => (r, n.parse().unwrap(), b),
-                            [n, "blue"] => (r, g, n.parse().unwrap()),
-                            _ => (r, g, b),
-                        }
-                    });
-
-                    Round {
-                        red: r,
-                        green: g,
-                        blue: b,
-                    }
+                    rs.split(',').try_fold(
+                        Round {
+                            red: 0,
+                            green: 0,
+                            blue: 0,
+                        },
+                        |round, c| {
+                            let cubes = c.trim();
+                            let count = |n: &str| {
+                                n.parse().map_err(|_| {
+                                    SolveError::at_token(i, l, n, format!("invalid count '{n}'"))
+                                })
+                            };
+
+                            match cubes.split(' ').collect::<Vec<_>>()[..] {
+                                [n, "red"] => Ok(Round {
+                                    red: count(n)?,
+                                    ..round
+                                }),
+                                [n, "green"] => Ok(Round {
+                                    green: count(n)?,
+                                    ..round
+                                }),
+                                [n, "blue"] => Ok(Round {
+                                    blue: count(n)?,
+                                    ..round
+                                }),
+                                _ => Err(SolveError::at_token(
+                                    i,
+                                    l,
+                                    cubes,
+                                    format!("unknown cubes '{cubes}'"),
+                                )),
+                            }
+                        },
+                    )
                 })
-                .collect();
+                .collect::<Result<_, _>>()?;
 
-            Game {
+            Ok(Game {
                 id: game_id,
                 rounds,
-            }
+            })
         })
         .collect()
 }
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     id: i32,
     rounds: Vec<Round>,
 }
@@ -87,3 +128,22 @@ struct Round {
     green: i32,
     blue: i32,
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green\n",
+    "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue\n",
+    "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red\n",
+    "Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red\n",
+    "Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green\n",
+);
+
+aoc_common::examples! {
+    solution: Day02,
+    part_one: {
+        EXAMPLE => "8",
+    },
+    part_two: {
+        EXAMPLE => "2286",
+    },
+}
@@ -1,94 +1,82 @@
-use std::{collections::HashSet, fs, path::PathBuf};
-
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
-    let grid: Vec<Vec<char>> = parse(&input);
-    let first = solve_first(&grid);
-    let second = solve_second(&grid);
-    (first.to_string(), second.to_string())
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Grid, Point, Solution, SolveError};
+
+pub struct Day03;
+
+aoc_common::register!(Day03);
+
+impl Solution for Day03 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Gear Ratios";
+
+    type Input = Grid<char>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Grid::parse(input, Ok)
+    }
+
+    fn part_one(&self, grid: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(grid).into())
+    }
+
+    fn part_two(&self, grid: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_second(grid).into())
+    }
 }
 
-fn solve_first(grid: &Vec<Vec<char>>) -> i32 {
+fn solve_first(grid: &Grid<char>) -> i32 {
     let mut part_numbers = HashSet::new();
-    for (y, line) in grid.iter().enumerate() {
-        for (x, &c) in line.iter().enumerate() {
-            if c == '.' || c.is_numeric() {
-                continue;
-            }
-
-            find_part_numbers(x, y, &grid, &mut part_numbers);
+    for (position, &c) in grid.iter() {
+        if c == '.' || c.is_numeric() {
+            continue;
         }
+
+        find_part_numbers(position, grid, &mut part_numbers);
     }
 
     part_numbers.iter().map(|(_, _, pn)| pn).sum()
 }
 
-fn solve_second(grid: &Vec<Vec<char>>) -> i32 {
+fn solve_second(grid: &Grid<char>) -> i32 {
     let mut gear_ratios = vec![];
-    for (y, line) in grid.iter().enumerate() {
-        for (x, &c) in line.iter().enumerate() {
-            if c != '*' {
-                continue;
-            }
-            let mut part_numbers = HashSet::new();
-
-            find_part_numbers(x, y, grid, &mut part_numbers);
-
-            match part_numbers.iter().collect::<Vec<_>>()[..] {
-                [(_, _, a), (_, _, b)] => {
-                    gear_ratios.push(a * b);
-                }
-                _ => (),
-            }
+    for (position, &c) in grid.iter() {
+        if c != '*' {
+            continue;
         }
-    }
+        let mut part_numbers = HashSet::new();
 
-    gear_ratios.iter().sum()
-}
+        find_part_numbers(position, grid, &mut part_numbers);
 
-fn parse(input: &str) -> Vec<Vec<char>> {
-    input
-        .lines()
-        .filter(|l| !l.trim().is_empty())
-        .map(|l| l.chars().collect())
-        .collect()
-}
-
-fn insert_some(item: Option<(usize, usize, i32)>, set: &mut HashSet<(usize, usize, i32)>) {
-    match item {
-        Some(num) => {
-            set.insert(num);
+        if let [(_, _, a), (_, _, b)] = part_numbers.iter().collect::<Vec<_>>()[..] {
+            gear_ratios.push(a * b);
         }
-        _ => (),
     }
-}
 
-fn find_part_number(x: usize, y: usize, grid: &Vec<Vec<char>>) -> Option<(usize, usize, i32)> {
-    let line_len = grid.first().unwrap().len();
-    if x > line_len || y > grid.len() {
-        return None;
-    }
+    gear_ratios.iter().sum()
+}
 
-    if !grid[y][x].is_numeric() {
+fn find_part_number(
+    Point { x, y }: Point<usize>,
+    grid: &Grid<char>,
+) -> Option<(usize, usize, i32)> {
+    let row = grid.row(y);
+    if !row[x].is_numeric() {
         return None;
     }
 
-    let start_idx = grid[y][..x]
+    let start_idx = row[..x]
         .iter()
-        .enumerate()
-        .rfind(|(_, c)| !c.is_numeric())
-        .and_then(|(i, _)| Some(i + 1))
+        .rposition(|c| !c.is_numeric())
+        .map(|i| i + 1)
         .unwrap_or(0);
-    let end_offset = grid[y][x..]
+    let end_offset = row[x..]
         .iter()
-        .enumerate()
-        .find(|(_, c)| !c.is_numeric())
-        .and_then(|(i, _)| Some(i))
-        .unwrap_or(line_len - x);
+        .position(|c| !c.is_numeric())
+        .unwrap_or(row.len() - x);
 
-    let num_str = grid[y][start_idx..x + end_offset]
-        .iter()
-        .collect::<String>();
+    let num_str = row[start_idx..x + end_offset].iter().collect::<String>();
 
     let num = num_str.parse().expect("must be a number");
 
@@ -96,25 +84,36 @@ fn find_part_number(x: usize, y: usize, grid: &Vec<Vec<char>>) -> Option<(usize,
 }
 
 fn find_part_numbers(
-    x: usize,
-    y: usize,
-    grid: &Vec<Vec<char>>,
+    position: Point<usize>,
+    grid: &Grid<char>,
     part_numbers: &mut HashSet<(usize, usize, i32)>,
 ) {
-    let top_left = find_part_number(x - 1, y - 1, &grid);
-    insert_some(top_left, part_numbers);
-    let top = find_part_number(x, y - 1, &grid);
-    insert_some(top, part_numbers);
-    let top_right = find_part_number(x + 1, y - 1, &grid);
-    insert_some(top_right, part_numbers);
-    let left = find_part_number(x - 1, y, &grid);
-    insert_some(left, part_numbers);
-    let right = find_part_number(x + 1, y, &grid);
-    insert_some(right, part_numbers);
-    let bottom_left = find_part_number(x - 1, y + 1, &grid);
-    insert_some(bottom_left, part_numbers);
-    let bottom = find_part_number(x, y + 1, &grid);
-    insert_some(bottom, part_numbers);
-    let bottom_right = find_part_number(x + 1, y + 1, &grid);
-    insert_some(bottom_right, part_numbers);
+    part_numbers.extend(
+        grid.neighbours8(position)
+            .filter_map(|neighbour| find_part_number(neighbour, grid)),
+    );
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "467..114..\n",
+    "...*......\n",
+    "..35..633.\n",
+    "......#...\n",
+    "617*......\n",
+    ".....+.58.\n",
+    "..592.....\n",
+    "......755.\n",
+    "...$.*....\n",
+    ".664.598..\n",
+);
+
+aoc_common::examples! {
+    solution: Day03,
+    part_one: {
+        EXAMPLE => "4361",
+    },
+    part_two: {
+        EXAMPLE => "467835",
+    },
 }
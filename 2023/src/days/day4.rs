@@ -1,37 +1,36 @@
-use std::{collections::HashSet, fs, path::PathBuf, usize};
+use std::collections::HashSet;
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+use aoc_common::{input, Answer, Solution, SolveError};
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+pub struct Day04;
 
-    (first.to_string(), second.to_string())
+aoc_common::register!(Day04);
+
+impl Solution for Day04 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Scratchcards";
+
+    type Input = Vec<ScratchCard>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_scratch_cards(input)
+    }
+
+    fn part_one(&self, scratch_cards: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(scratch_cards).into())
+    }
+
+    fn part_two(&self, scratch_cards: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_second(scratch_cards).into())
+    }
 }
 
-fn solve_first(input: &str) -> i32 {
-    input
-        .lines()
-        .filter(|l| !l.trim().is_empty())
-        .map(|l| {
-            let cards = l[8..]
-                .split('|')
-                .map(|nums| {
-                    nums.split(' ')
-                        .filter_map(|n| n.trim().parse::<i32>().ok())
-                        .collect::<HashSet<i32>>()
-                })
-                .collect::<Vec<_>>();
-            let (winning_numbers, scratched_numbers) = match &cards[..] {
-                [first, second] => (first, second),
-                _ => unreachable!(),
-            };
-
-            let count: u32 = winning_numbers
-                .intersection(&scratched_numbers)
-                .count()
-                .try_into()
-                .unwrap();
+fn solve_first(scratch_cards: &[ScratchCard]) -> i32 {
+    scratch_cards
+        .iter()
+        .map(|scratch_card| {
+            let count: u32 = scratch_card.matching_numbers().try_into().unwrap();
 
             if count == 0 {
                 return 0;
@@ -44,30 +43,17 @@ fn solve_first(input: &str) -> i32 {
         .sum()
 }
 
-fn solve_second(input: &str) -> usize {
-    let cards: Vec<Card> = input
-        .lines()
-        .filter(|l| !l.trim().is_empty())
+fn solve_second(scratch_cards: &[ScratchCard]) -> usize {
+    let cards: Vec<Card> = scratch_cards
+        .iter()
         .enumerate()
-        .map(|(id, l)| {
-            let card = l[8..]
-                .split('|')
-                .map(|nums| {
-                    nums.split(' ')
-                        .filter_map(|n| n.trim().parse::<i32>().ok())
-                        .collect::<HashSet<i32>>()
-                })
-                .collect::<Vec<_>>();
-            let (winning_numbers, scratched_numbers) = match &card[..] {
-                [first, second] => (first, second),
-                _ => unreachable!(),
-            };
-
-            let count = winning_numbers.intersection(&scratched_numbers).count();
+        .map(|(id, scratch_card)| {
+            let count = scratch_card.matching_numbers();
 
             Card {
                 id,
-                won_copies: (id + 1..id + count + 1).collect(),
+                // copies past the last card are not won, e.g. in truncated input
+                won_copies: (id + 1..(id + count + 1).min(scratch_cards.len())).collect(),
             }
         })
         .collect();
@@ -89,8 +75,84 @@ fn evaluate_cards(current_card: &usize, all_cards: &Vec<Card>) -> usize {
     count
 }
 
+fn parse_scratch_cards(input: &str) -> Result<Vec<ScratchCard>, SolveError> {
+    input::lines(input)
+        .filter(|l| !l.is_blank())
+        .map(|line| {
+            let (_, numbers) = line
+                .text
+                .split_once(':')
+                .ok_or_else(|| line.error_at(0, "card must start with 'Card <id>:'"))?;
+            let numbers = numbers
+                .split('|')
+                .map(|nums| {
+                    nums.split_whitespace()
+                        .map(|n| {
+                            n.parse::<i32>().map_err(|_| {
+                                line.error_at_token(n, format!("invalid number '{n}'"))
+                            })
+                        })
+                        .collect::<Result<HashSet<i32>, _>>()
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+
+            match &numbers[..] {
+                [winning_numbers, scratched_numbers] => Ok(ScratchCard {
+                    winning_numbers: winning_numbers.clone(),
+                    scratched_numbers: scratched_numbers.clone(),
+                }),
+                _ => {
+                    Err(line.error_at(0, "winning and scratched numbers must be separated by '|'"))
+                }
+            }
+        })
+        .collect()
+}
+
+#[derive(Debug)]
+pub struct ScratchCard {
+    winning_numbers: HashSet<i32>,
+    scratched_numbers: HashSet<i32>,
+}
+
+impl ScratchCard {
+    fn matching_numbers(&self) -> usize {
+        self.winning_numbers
+            .intersection(&self.scratched_numbers)
+            .count()
+    }
+}
+
 #[derive(Debug)]
 struct Card {
     id: usize,
     won_copies: Vec<usize>,
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n",
+    "Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19\n",
+    "Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1\n",
+    "Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83\n",
+    "Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36\n",
+    "Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11\n",
+);
+
+aoc_common::examples! {
+    solution: Day04,
+    part_one: {
+        EXAMPLE => "13",
+    },
+    part_two: {
+        EXAMPLE => "30",
+    },
+}
+
+#[test]
+fn test_truncated_cards() {
+    // card 3 would win copies of cards 4 to 7
+    let input = EXAMPLE.lines().take(3).collect::<Vec<_>>().join("\n");
+    let cards = Day04.parse(&input).unwrap();
+    assert_eq!(Day04.part_two(&cards), Ok(Answer::from(7usize)));
+}
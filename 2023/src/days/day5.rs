@@ -1,62 +1,76 @@
+use std::collections::HashMap;
+
+use aoc_common::{
+    input::{self, Block, Line},
+    parse::Cursor,
+    Answer, Solution, SolveError,
+};
 use rayon::prelude::*;
-use std::{collections::HashMap, fs, path::PathBuf};
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+pub struct Day05;
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+aoc_common::register!(Day05);
 
-    (first.to_string(), second.to_string())
-}
+impl Solution for Day05 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "If You Give A Seed A Fertilizer";
+
+    type Input = SeedAlmanac;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let mut blocks = input::blocks(input);
+        let seeds_block = blocks
+            .next()
+            .ok_or_else(|| SolveError::new("the almanac is empty"))?;
+        let [seeds_line] = seeds_block.lines() else {
+            return Err(seeds_block.error("first block must only list the seeds"));
+        };
 
-fn solve_first(input: &str) -> u64 {
-    let lines: Vec<&str> = input.lines().collect();
-    let seeds: Vec<u64> = lines[0][7..]
-        .split(' ')
-        .map(|s| {
-            s.parse()
-                .expect(format!("seed must be a number but is '{}'", s).as_str())
+        let mut cursor = seeds_line.value_of("seeds")?;
+        let seeds = cursor.separated(" ", Cursor::number)?;
+        cursor.end()?;
+
+        Ok(SeedAlmanac {
+            seeds,
+            almanac: parse_almanac(blocks)?,
         })
-        .collect();
+    }
 
-    let almanac = parse_almanac(lines);
+    fn part_one(&self, seed_almanac: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(seed_almanac).into())
+    }
 
-    let lowest_location = seeds
+    fn part_two(&self, seed_almanac: &Self::Input) -> Result<Answer, SolveError> {
+        solve_second(seed_almanac)
+            .map(Answer::from)
+            .ok_or_else(|| SolveError::new("there has to be at least one range of seeds"))
+    }
+}
+
+fn solve_first(seed_almanac: &SeedAlmanac) -> u64 {
+    let lowest_location = seed_almanac
+        .seeds
         .iter()
-        .map(|s| lookup_location(*s, &almanac))
+        .map(|s| lookup_location(*s, &seed_almanac.almanac))
         .min()
-        .unwrap();
+        .expect("there is at least one seed");
 
     lowest_location
 }
 
-fn solve_second(input: &str) -> u64 {
-    let lines: Vec<&str> = input.lines().collect();
-    let seed_instructions = lines[0][7..]
-        .split(' ')
-        .map(|s| {
-            s.parse()
-                .expect(format!("must be a number but is '{}'", s).as_str())
-        })
-        .collect::<Vec<u64>>();
-    let seeds = seed_instructions[..]
+fn solve_second(seed_almanac: &SeedAlmanac) -> Option<u64> {
+    let seeds = seed_almanac.seeds[..]
         .chunks_exact(2)
         .flat_map(|c| match c {
-            [start, count] => [*start..*start + *count].into_iter(),
+            [start, count] => *start..*start + *count,
             _ => unreachable!(),
-        })
-        .flatten();
+        });
 
-    let almanac = parse_almanac(lines);
-
-    let lowest_location = seeds
+    seeds
         .par_bridge()
-        .map(|s| lookup_location(s, &almanac))
+        .map(|s| lookup_location(s, &seed_almanac.almanac))
         .min()
-        .unwrap();
-
-    lowest_location
 }
 
 fn lookup_location(seed: u64, almanac: &Almanac) -> u64 {
@@ -92,58 +106,53 @@ fn lookup_location(seed: u64, almanac: &Almanac) -> u64 {
     location
 }
 
-fn parse_almanac(lines: Vec<&str>) -> Almanac {
-    let mut parse_state = ParseState {
-        current_map_type: None,
-        almanac: HashMap::with_capacity(7),
-    };
-
-    for line in &lines[1..] {
-        if line.trim().is_empty() {
-            parse_state.current_map_type = None;
-        } else if line.chars().next().unwrap().is_alphabetic() {
-            let new_map_type = parse_map_type(line.trim_end_matches(" map:"));
-            parse_state.current_map_type = new_map_type;
-            parse_state
-                .almanac
-                .insert(parse_state.current_map_type.unwrap(), vec![]);
-        } else if line.chars().next().unwrap().is_numeric() {
-            if parse_state.current_map_type.is_none() {
-                panic!("line starts with numeric but no map type is set");
-            }
-            let map = match line
-                .split(' ')
-                .map(|s| s.parse::<u64>().unwrap())
-                .collect::<Vec<u64>>()[..]
-            {
-                [destination, source, range] => Map {
-                    source,
-                    destination,
-                    range,
-                },
-                _ => panic!("there must be three numbers"),
-            };
-
-            if let Some(maps) = parse_state
-                .almanac
-                .get_mut(&parse_state.current_map_type.unwrap())
-            {
-                maps.push(map);
-            }
-        } else {
-            panic!("line starts with unexpected character");
-        }
+fn parse_almanac<'a>(blocks: impl Iterator<Item = Block<'a>>) -> Result<Almanac, SolveError> {
+    let mut almanac = HashMap::with_capacity(7);
+
+    for block in blocks {
+        let (header, lines) = block.lines().split_first().expect("blocks are never empty");
+
+        let mut cursor = header.cursor();
+        let name_start = cursor;
+        let name = cursor.until(" map:")?;
+        cursor.end()?;
+        let map_type = parse_map_type(name)
+            .ok_or_else(|| name_start.error(format!("unknown map '{name}'")))?;
+
+        let maps = lines.iter().map(parse_map).collect::<Result<_, _>>()?;
+        almanac.insert(map_type, maps);
     }
 
-    parse_state.almanac
+    if let Some(missing) = MapType::ALL.iter().find(|t| !almanac.contains_key(t)) {
+        return Err(SolveError::new(format!("almanac has no {missing:?} map")));
+    }
+
+    Ok(almanac)
+}
+
+/// Map given as destination, source and range, e.g. `50 98 2`.
+fn parse_map(line: &Line) -> Result<Map, SolveError> {
+    let mut cursor = line.cursor();
+    let destination = cursor.number()?;
+    cursor.expect(" ")?;
+    let source = cursor.number()?;
+    cursor.expect(" ")?;
+    let range = cursor.number()?;
+    cursor.end()?;
+
+    Ok(Map {
+        source,
+        destination,
+        range,
+    })
 }
 
 type Almanac = HashMap<MapType, Vec<Map>>;
 
 #[derive(Debug)]
-struct ParseState {
-    current_map_type: Option<MapType>,
-    almanac: HashMap<MapType, Vec<Map>>,
+pub struct SeedAlmanac {
+    seeds: Vec<u64>,
+    almanac: Almanac,
 }
 
 fn parse_map_type(input: &str) -> Option<MapType> {
@@ -160,7 +169,7 @@ fn parse_map_type(input: &str) -> Option<MapType> {
 }
 
 #[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
-enum MapType {
+pub enum MapType {
     SeedToSoil,
     SoilToFertilizer,
     FertilizerToWater,
@@ -170,8 +179,20 @@ enum MapType {
     HumidityToLocation,
 }
 
+impl MapType {
+    const ALL: [MapType; 7] = [
+        MapType::SeedToSoil,
+        MapType::SoilToFertilizer,
+        MapType::FertilizerToWater,
+        MapType::WaterToLight,
+        MapType::LightToTemperature,
+        MapType::TemperatureToHumidity,
+        MapType::HumidityToLocation,
+    ];
+}
+
 #[derive(Debug)]
-struct Map {
+pub struct Map {
     source: u64,
     destination: u64,
     range: u64,
@@ -180,9 +201,56 @@ struct Map {
 impl Map {
     fn try_map(&self, value: u64) -> Option<u64> {
         if value >= self.source && value < self.source + self.range {
-            let mapped = self.destination + 1 * (value - self.source);
+            let mapped = self.destination + (value - self.source);
             return Some(mapped);
         }
         None
     }
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "seeds: 79 14 55 13\n",
+    "\n",
+    "seed-to-soil map:\n",
+    "50 98 2\n",
+    "52 50 48\n",
+    "\n",
+    "soil-to-fertilizer map:\n",
+    "0 15 37\n",
+    "37 52 2\n",
+    "39 0 15\n",
+    "\n",
+    "fertilizer-to-water map:\n",
+    "49 53 8\n",
+    "0 11 42\n",
+    "42 0 7\n",
+    "57 7 4\n",
+    "\n",
+    "water-to-light map:\n",
+    "88 18 7\n",
+    "18 25 70\n",
+    "\n",
+    "light-to-temperature map:\n",
+    "45 77 23\n",
+    "81 45 19\n",
+    "68 64 13\n",
+    "\n",
+    "temperature-to-humidity map:\n",
+    "0 69 1\n",
+    "1 0 69\n",
+    "\n",
+    "humidity-to-location map:\n",
+    "60 56 37\n",
+    "56 93 4\n",
+);
+
+aoc_common::examples! {
+    solution: Day05,
+    part_one: {
+        EXAMPLE => "35",
+    },
+    part_two: {
+        EXAMPLE => "46",
+    },
+}
@@ -1,18 +1,51 @@
-use std::{fs, path::PathBuf};
+use aoc_common::{Answer, Solution, SolveError};
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+pub struct Day06;
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+aoc_common::register!(Day06);
 
-    (first.to_string(), second.to_string())
+impl Solution for Day06 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Wait For It";
+
+    type Input = RaceSheet;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let times = lines
+            .first()
+            .and_then(|l| l.strip_prefix("Time:"))
+            .ok_or_else(|| SolveError::at(0, 0, "first line must start with 'Time:'"))?;
+        let distances = lines
+            .get(1)
+            .and_then(|l| l.strip_prefix("Distance:"))
+            .ok_or_else(|| SolveError::at(1, 0, "second line must start with 'Distance:'"))?;
+
+        Ok(RaceSheet {
+            races: parse_races_first(&lines, times, distances)?,
+            race: parse_race_second(times, distances)?,
+        })
+    }
+
+    fn part_one(&self, race_sheet: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(&race_sheet.races).into())
+    }
+
+    fn part_two(&self, race_sheet: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_second(&race_sheet.race).into())
+    }
 }
 
-fn solve_first(input: &str) -> u64 {
-    let races = parse_races_first(&input);
+/// The sheet of paper can either be read as many short races or as one long race.
+#[derive(Debug)]
+pub struct RaceSheet {
+    races: Vec<Race>,
+    race: Race,
+}
 
-    races.iter().map(|r| number_wins(r)).product()
+fn solve_first(races: &[Race]) -> u64 {
+    races.iter().map(number_wins).product()
 }
 
 fn number_wins(race: &Race) -> u64 {
@@ -31,40 +64,58 @@ fn number_wins(race: &Race) -> u64 {
     number_of_wins
 }
 
-fn solve_second(input: &str) -> u64 {
-    let race = parse_race_second(input);
-    number_wins(&race)
+fn solve_second(race: &Race) -> u64 {
+    number_wins(race)
 }
 
-fn parse_races_first(input: &str) -> Vec<Race> {
-    let lines: Vec<&str> = input.lines().collect();
-    let times = lines[0][6..].split(' ').filter_map(|s| {
-        if s.is_empty() {
-            None
-        } else {
-            s.parse::<u64>().ok()
-        }
-    });
-    let distances = lines[1][10..].split(' ').filter_map(|s| {
-        if s.is_empty() {
-            None
-        } else {
-            s.parse::<u64>().ok()
-        }
-    });
+fn parse_races_first(
+    lines: &[&str],
+    times: &str,
+    distances: &str,
+) -> Result<Vec<Race>, SolveError> {
+    let numbers = |line_index: usize, values: &str| {
+        values
+            .split_whitespace()
+            .map(|s| {
+                s.parse::<u64>().map_err(|_| {
+                    SolveError::at_token(
+                        line_index,
+                        lines[line_index],
+                        s,
+                        format!("invalid number '{s}'"),
+                    )
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()
+    };
+    let times = numbers(0, times)?;
+    let distances = numbers(1, distances)?;
+    if times.len() != distances.len() {
+        return Err(SolveError::at(
+            1,
+            0,
+            "every race needs a time and a distance",
+        ));
+    }
 
-    times
+    Ok(times
+        .into_iter()
         .zip(distances)
         .map(|(time, distance)| Race { time, distance })
-        .collect()
+        .collect())
 }
 
-fn parse_race_second(input: &str) -> Race {
-    let lines: Vec<&str> = input.lines().collect();
-    let time = lines[0][6..].replace(' ', "").parse().unwrap();
-    let distance = lines[1][10..].replace(' ', "").parse().unwrap();
+fn parse_race_second(times: &str, distances: &str) -> Result<Race, SolveError> {
+    let time = times
+        .replace(' ', "")
+        .parse()
+        .map_err(|_| SolveError::at(0, 0, "time of the long race is not a number"))?;
+    let distance = distances
+        .replace(' ', "")
+        .parse()
+        .map_err(|_| SolveError::at(1, 0, "distance of the long race is not a number"))?;
 
-    Race { time, distance }
+    Ok(Race { time, distance })
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -72,3 +123,16 @@ struct Race {
     time: u64,
     distance: u64,
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!("Time:      7  15   30\n", "Distance:  9  40  200\n");
+
+aoc_common::examples! {
+    solution: Day06,
+    part_one: {
+        EXAMPLE => "288",
+    },
+    part_two: {
+        EXAMPLE => "71503",
+    },
+}
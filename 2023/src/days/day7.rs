@@ -1,35 +1,36 @@
 use std::{
     collections::HashMap,
     fmt::{self, Display},
-    fs,
-    path::PathBuf,
 };
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+use aoc_common::{Answer, Solution, SolveError};
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+pub struct Day07;
 
-    (first.to_string(), second.to_string())
-}
+aoc_common::register!(Day07);
 
-fn solve_first(input: &str) -> u32 {
-    let mut games: Vec<Game> = input
-        .lines()
-        .filter(|l| !l.trim().is_empty())
-        .map(|l| {
-            let cards: [Card; 5] = l[..5].chars().map(|c| char_to_card(&c)).collect::<Vec<_>>()[..]
-                .try_into()
-                .unwrap();
-            let bid: u32 = l[6..].parse().unwrap();
+impl Solution for Day07 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 7;
+    const TITLE: &'static str = "Camel Cards";
 
-            Game {
-                bid,
-                hand: Hand::new(cards),
-            }
-        })
-        .collect();
+    type Input = Vec<Game>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_games(input)
+    }
+
+    fn part_one(&self, games: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(games).into())
+    }
+
+    fn part_two(&self, _games: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::NotImplemented)
+    }
+}
+
+fn solve_first(games: &[Game]) -> u32 {
+    let mut games: Vec<&Game> = games.iter().collect();
 
     games.sort_unstable_by(|lhs, rhs| rhs.hand.cmp(&lhs.hand));
 
@@ -45,24 +46,51 @@ fn solve_first(input: &str) -> u32 {
         .sum()
 }
 
-fn solve_second(_input: &str) -> u64 {
-    0
+fn parse_games(input: &str) -> Result<Vec<Game>, SolveError> {
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, l)| {
+            let (hand, bid) = l
+                .split_once(' ')
+                .ok_or_else(|| SolveError::at(i, 0, "hand and bid must be separated by a space"))?;
+            let cards: [Card; 5] = hand
+                .chars()
+                .enumerate()
+                .map(|(x, c)| {
+                    char_to_card(&c)
+                        .ok_or_else(|| SolveError::at(i, x, format!("unsupported card '{c}'")))
+                })
+                .collect::<Result<Vec<_>, _>>()?[..]
+                .try_into()
+                .map_err(|_| SolveError::at(i, 0, "a hand must have five cards"))?;
+            let bid: u32 = bid
+                .parse()
+                .map_err(|_| SolveError::at_token(i, l, bid, format!("invalid bid '{bid}'")))?;
+
+            Ok(Game {
+                bid,
+                hand: Hand::new(cards),
+            })
+        })
+        .collect()
 }
 
-fn char_to_card(c: &char) -> Card {
+fn char_to_card(c: &char) -> Option<Card> {
     match c {
-        'A' => Card::A,
-        'K' => Card::K,
-        'Q' => Card::Q,
-        'J' => Card::J,
-        'T' => Card::T,
-        n if n.is_numeric() => Card::Number(n.to_digit(10).unwrap().try_into().unwrap()),
-        _ => panic!("unsupported char"),
+        'A' => Some(Card::A),
+        'K' => Some(Card::K),
+        'Q' => Some(Card::Q),
+        'J' => Some(Card::J),
+        'T' => Some(Card::T),
+        n @ '2'..='9' => n.to_digit(10).map(|d| Card::Number(d as u8)),
+        _ => None,
     }
 }
 
 #[derive(Debug)]
-struct Game {
+pub struct Game {
     hand: Hand,
     bid: u32,
 }
@@ -86,12 +114,7 @@ impl Ord for Hand {
 
 impl PartialOrd for Hand {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        match self.hand_type.partial_cmp(&other.hand_type) {
-            Some(core::cmp::Ordering::Equal) => {}
-            ord => return ord,
-        }
-
-        other.cards.partial_cmp(&self.cards)
+        Some(self.cmp(other))
     }
 }
 
@@ -211,6 +234,22 @@ impl Ord for Card {
 
 impl PartialOrd for Card {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        self.rank().partial_cmp(&other.rank())
+        Some(self.cmp(other))
     }
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "32T3K 765\n",
+    "T55J5 684\n",
+    "KK677 28\n",
+    "KTJJT 220\n",
+    "QQQJA 483\n",
+);
+
+aoc_common::examples! {
+    solution: Day07,
+    part_one: {
+        EXAMPLE => "6440",
+    },
+}
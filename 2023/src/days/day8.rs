@@ -1,29 +1,62 @@
-use std::{
-    collections::{HashMap, HashSet},
-    fs,
-    path::PathBuf,
-};
+use std::collections::HashMap;
 
+use aoc_common::{number_theory::chinese_remainder, Answer, Solution, SolveError};
 use regex::Regex;
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+pub struct Day08;
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+aoc_common::register!(Day08);
 
-    (first.to_string(), second.to_string())
+impl Solution for Day08 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 8;
+    const TITLE: &'static str = "Haunted Wasteland";
+
+    type Input = Network;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        let lines: Vec<&str> = input.lines().collect();
+        let instructions: Vec<char> = lines.first().copied().unwrap_or_default().chars().collect();
+        if instructions.is_empty() {
+            return Err(SolveError::at(0, 0, "first line must contain instructions"));
+        }
+        if let Some(x) = instructions.iter().position(|c| *c != 'L' && *c != 'R') {
+            return Err(SolveError::at(0, x, "unexpected instruction"));
+        }
+
+        Ok(Network {
+            instructions,
+            map: parse_paths(lines.get(2..).unwrap_or_default())?,
+        })
+    }
+
+    fn part_one(&self, network: &Self::Input) -> Result<Answer, SolveError> {
+        if !network.map.contains_key("AAA") {
+            return Err(SolveError::new("there is no node AAA to start at"));
+        }
+
+        Ok(solve_first(network).into())
+    }
+
+    fn part_two(&self, network: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_second(network)?.into())
+    }
 }
 
-fn solve_first(input: &str) -> usize {
-    let lines: Vec<&str> = input.lines().collect();
-    let instructions = lines[0].chars();
-    let map: HashMap<String, Paths> = parse_paths(&lines[2..]);
+/// Left/right instructions together with the map of nodes they are applied to.
+#[derive(Debug)]
+pub struct Network {
+    instructions: Vec<char>,
+    map: HashMap<String, Paths>,
+}
+
+fn solve_first(network: &Network) -> usize {
+    let Network { instructions, map } = network;
 
     const GOAL: &str = "ZZZ";
     let mut current = "AAA";
     let mut steps = 0;
-    for instruction in instructions.cycle() {
+    for instruction in instructions.iter().cycle() {
         if current == GOAL {
             break;
         }
@@ -39,55 +72,113 @@ fn solve_first(input: &str) -> usize {
     steps
 }
 
-fn solve_second(input: &str) -> usize {
-    let lines: Vec<&str> = input.lines().collect();
-    let instructions = lines[0].chars();
-    let map: HashMap<String, Paths> = parse_paths(&lines[2..]);
-
-    let mut positions: Vec<&str> = map
+/// Instead of moving all ghosts in lock-step, which takes far too many steps, finds the
+/// cycle of each ghost and the first step at which the goals of all cycles line up.
+fn solve_second(network: &Network) -> Result<usize, SolveError> {
+    let cycles: Vec<Cycle> = network
+        .map
         .keys()
-        .filter_map(|k| {
-            if k.ends_with('A') {
-                Some(k.as_str())
-            } else {
-                None
-            }
-        })
+        .filter(|node| node.ends_with('A'))
+        .map(|node| find_cycle(network, node))
         .collect();
+    let Some(start) = cycles.iter().map(|cycle| cycle.start).max() else {
+        return Err(SolveError::new(
+            "there are no nodes ending with A to start at",
+        ));
+    };
 
-    let possible_goals: HashSet<&str> = map
-        .keys()
-        .filter_map(|k| {
-            if k.ends_with('Z') {
-                Some(k.as_str())
-            } else {
-                None
-            }
+    // before all ghosts are in their cycles only the steps themselves can be compared
+    if let Some(steps) = (0..start).find(|&steps| cycles.iter().all(|c| c.is_goal_at(steps))) {
+        return Ok(steps);
+    }
+
+    // steps at which the ghosts so far are all on goals, as remainder and period
+    let mut solutions = vec![(0, 1)];
+    for cycle in &cycles {
+        let length = cycle.length as i64;
+        let goals: Vec<i64> = cycle
+            .goals
+            .iter()
+            .filter(|&&goal| goal >= cycle.start)
+            .map(|&goal| goal as i64)
+            .collect();
+        solutions = solutions
+            .into_iter()
+            .flat_map(|solution| {
+                goals
+                    .iter()
+                    .filter_map(move |&goal| chinese_remainder([solution, (goal, length)]))
+            })
+            .collect();
+    }
+
+    solutions
+        .into_iter()
+        .map(|(remainder, period)| {
+            let (remainder, period) = (remainder as usize, period as usize);
+            // all ghosts have to be in their cycles for the remainders to hold
+            remainder + start.saturating_sub(remainder).div_ceil(period) * period
         })
-        .collect();
+        .min()
+        .ok_or_else(|| SolveError::new("the ghosts are never all on nodes ending with Z"))
+}
 
-    let mut steps = 0;
-    for instruction in instructions.cycle() {
-        if positions.iter().all(|pos| possible_goals.contains(pos)) {
-            break;
+/// Steps at which a ghost is on a node ending with Z. They repeat as soon as the ghost is on
+/// a node at the same instruction a second time.
+#[derive(Debug, PartialEq, Eq)]
+struct Cycle {
+    /// Steps before the ghost enters the cycle.
+    start: usize,
+    length: usize,
+    /// Goals before `start` are only reached once, the others every `length` steps.
+    goals: Vec<usize>,
+}
+
+impl Cycle {
+    fn is_goal_at(&self, steps: usize) -> bool {
+        if steps < self.start {
+            return self.goals.contains(&steps);
         }
 
-        for i in 0..positions.len() {
-            let paths = &map[positions[i]];
-            positions[i] = match instruction {
-                'L' => &paths.left,
-                'R' => &paths.right,
-                _ => unreachable!("unexpected instruction"),
+        self.goals
+            .iter()
+            .any(|&goal| goal >= self.start && steps.abs_diff(goal) % self.length == 0)
+    }
+}
+
+fn find_cycle(network: &Network, start: &str) -> Cycle {
+    let Network { instructions, map } = network;
+
+    // steps after which the ghost was first on a node at an instruction
+    let mut seen: HashMap<(&str, usize), usize> = HashMap::new();
+    let mut goals = Vec::new();
+    let mut current = start;
+    for steps in 0.. {
+        let instruction = steps % instructions.len();
+        if let Some(&first) = seen.get(&(current, instruction)) {
+            return Cycle {
+                start: first,
+                length: steps - first,
+                goals,
             };
         }
+        seen.insert((current, instruction), steps);
+        if current.ends_with('Z') {
+            goals.push(steps);
+        }
 
-        steps += 1;
+        let paths = &map[current];
+        current = match instructions[instruction] {
+            'L' => &paths.left,
+            'R' => &paths.right,
+            _ => unreachable!("unexpected instruction"),
+        };
     }
 
-    steps
+    unreachable!("there are only finitely many nodes and instructions")
 }
 
-fn parse_paths(lines: &[&str]) -> HashMap<String, Paths> {
+fn parse_paths(lines: &[&str]) -> Result<HashMap<String, Paths>, SolveError> {
     const KEY_GROUP: &str = "key";
     const LEFT_GROUP: &str = "left";
     const RIGHT_GROUP: &str = "right";
@@ -96,28 +187,120 @@ fn parse_paths(lines: &[&str]) -> HashMap<String, Paths> {
     );
     let re: Regex = Regex::new(pattern.as_str()).unwrap();
 
-    lines
+    // the paths start after the instructions and an empty line
+    let first_line = 2;
+    let map = lines
         .iter()
-        .map(|line| {
-            let groups = re.captures(line).expect("regex must match");
+        .enumerate()
+        .map(|(i, line)| {
+            let groups = re.captures(line).ok_or_else(|| {
+                SolveError::at(first_line + i, 0, "expected a node like 'AAA = (BBB, CCC)'")
+            })?;
 
             let key = &groups[KEY_GROUP];
             let left = &groups[LEFT_GROUP];
             let right = &groups[RIGHT_GROUP];
 
-            (
+            Ok((
                 key.to_owned(),
                 Paths {
                     left: left.to_owned(),
                     right: right.to_owned(),
                 },
-            )
+            ))
         })
-        .collect()
+        .collect::<Result<HashMap<_, _>, SolveError>>()?;
+
+    if let Some((i, line)) = lines.iter().enumerate().find(|(_, line)| {
+        let paths = &re.captures(line).expect("all lines have been matched");
+        !map.contains_key(&paths[LEFT_GROUP]) || !map.contains_key(&paths[RIGHT_GROUP])
+    }) {
+        return Err(SolveError::at(
+            first_line + i,
+            0,
+            format!("'{line}' leads to an unknown node"),
+        ));
+    }
+
+    Ok(map)
 }
 
 #[derive(Debug)]
-struct Paths {
+pub struct Paths {
     left: String,
     right: String,
 }
+
+#[cfg(test)]
+const EXAMPLE_ONE: &str = concat!(
+    "RL\n",
+    "\n",
+    "AAA = (BBB, CCC)\n",
+    "BBB = (DDD, EEE)\n",
+    "CCC = (ZZZ, GGG)\n",
+    "DDD = (DDD, DDD)\n",
+    "EEE = (EEE, EEE)\n",
+    "GGG = (GGG, GGG)\n",
+    "ZZZ = (ZZZ, ZZZ)\n",
+);
+
+#[cfg(test)]
+const EXAMPLE_TWO: &str = concat!(
+    "LLR\n",
+    "\n",
+    "AAA = (BBB, BBB)\n",
+    "BBB = (AAA, ZZZ)\n",
+    "ZZZ = (ZZZ, ZZZ)\n",
+);
+
+#[cfg(test)]
+const EXAMPLE_GHOSTS: &str = concat!(
+    "LR\n",
+    "\n",
+    "11A = (11B, XXX)\n",
+    "11B = (XXX, 11Z)\n",
+    "11Z = (11B, XXX)\n",
+    "22A = (22B, XXX)\n",
+    "22B = (22C, 22C)\n",
+    "22C = (22Z, 22Z)\n",
+    "22Z = (22B, 22B)\n",
+    "XXX = (XXX, XXX)\n",
+);
+
+#[cfg(test)]
+const EXAMPLE_OFFSETS: &str = concat!(
+    "L\n",
+    "\n",
+    "11A = (11Z, 11Z)\n",
+    "11Z = (11B, 11B)\n",
+    "11B = (11Z, 11Z)\n",
+    "22A = (22B, 22B)\n",
+    "22B = (22C, 22C)\n",
+    "22C = (22Z, 22Z)\n",
+    "22Z = (22B, 22B)\n",
+);
+
+aoc_common::examples! {
+    solution: Day08,
+    part_one: {
+        EXAMPLE_ONE => "2",
+        EXAMPLE_TWO => "6",
+    },
+    part_two: {
+        EXAMPLE_GHOSTS => "6",
+        EXAMPLE_OFFSETS => "3",
+    },
+}
+
+#[test]
+fn test_find_cycle() {
+    let network = Day08.parse(EXAMPLE_OFFSETS).unwrap();
+    assert_eq!(
+        find_cycle(&network, "22A"),
+        Cycle {
+            start: 1,
+            length: 3,
+            goals: vec![3],
+        }
+    );
+}
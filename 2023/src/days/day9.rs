@@ -1,20 +1,42 @@
-use std::{fs, path::PathBuf};
+use aoc_common::{Answer, Solution, SolveError};
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+pub struct Day09;
 
-    let sequences: Vec<Vec<i64>> = input
-        .lines()
-        .map(|l| l.split(' ').map(|n| n.parse().unwrap()).collect())
-        .collect();
+aoc_common::register!(Day09);
+
+impl Solution for Day09 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 9;
+    const TITLE: &'static str = "Mirage Maintenance";
+
+    type Input = Vec<Vec<i64>>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                l.split(' ')
+                    .map(|n| {
+                        n.parse().map_err(|_| {
+                            SolveError::at_token(i, l, n, format!("invalid number '{n}'"))
+                        })
+                    })
+                    .collect()
+            })
+            .collect()
+    }
 
-    let first = solve_first(&sequences);
-    let second = solve_second(&sequences);
+    fn part_one(&self, sequences: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(sequences).into())
+    }
 
-    (first.to_string(), second.to_string())
+    fn part_two(&self, sequences: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_second(sequences).into())
+    }
 }
 
-fn solve_first(sequences: &Vec<Vec<i64>>) -> i64 {
+fn solve_first(sequences: &[Vec<i64>]) -> i64 {
     let result = sequences
         .iter()
         .map(|sequence| {
@@ -31,7 +53,7 @@ fn solve_first(sequences: &Vec<Vec<i64>>) -> i64 {
     result
 }
 
-fn solve_second(sequences: &Vec<Vec<i64>>) -> i64 {
+fn solve_second(sequences: &[Vec<i64>]) -> i64 {
     let result = sequences
         .iter()
         .map(|sequence| {
@@ -48,7 +70,7 @@ fn solve_second(sequences: &Vec<Vec<i64>>) -> i64 {
     result
 }
 
-fn find_null_sequence(sequence: &Vec<i64>) -> Vec<Vec<i64>> {
+fn find_null_sequence(sequence: &[i64]) -> Vec<Vec<i64>> {
     if sequence.iter().all(|n| *n == 0) {
         return vec![];
     }
@@ -66,3 +88,16 @@ fn find_null_sequence(sequence: &Vec<i64>) -> Vec<Vec<i64>> {
 
     result
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!("0 3 6 9 12 15\n", "1 3 6 10 15 21\n", "10 13 16 21 30 45\n");
+
+aoc_common::examples! {
+    solution: Day09,
+    part_one: {
+        EXAMPLE => "114",
+    },
+    part_two: {
+        EXAMPLE => "2",
+    },
+}
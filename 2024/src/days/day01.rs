@@ -1,30 +1,51 @@
-use std::{fs, path::PathBuf};
+use aoc_common::{Answer, Solution, SolveError};
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+pub struct Day01;
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+aoc_common::register!(Day01);
 
-    (first.to_string(), second.to_string())
-}
+impl Solution for Day01 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 1;
+    const TITLE: &'static str = "Historian Hysteria";
+
+    type Input = (Vec<i64>, Vec<i64>);
 
-fn solve_first(input: &str) -> i64 {
-    let mut columns =
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
         input
             .lines()
-            .filter(|l| !l.trim().is_empty())
-            .fold((vec![], vec![]), |mut acc, l| {
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty())
+            .try_fold((vec![], vec![]), |mut acc, (i, l)| {
                 let nums: Vec<i64> = l
                     .split_whitespace()
-                    .map(|s| s.parse::<i64>().expect("This must be a number"))
-                    .collect();
+                    .map(|s| {
+                        s.parse::<i64>()
+                            .map_err(|_| SolveError::at_token(i, l, s, "This must be a number"))
+                    })
+                    .collect::<Result<_, _>>()?;
+
+                let [left, right] = nums[..] else {
+                    return Err(SolveError::at(i, 0, "there must be two numbers"));
+                };
+                acc.0.push(left);
+                acc.1.push(right);
+
+                Ok(acc)
+            })
+    }
 
-                acc.0.push(nums[0]);
-                acc.1.push(nums[1]);
+    fn part_one(&self, columns: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(columns).into())
+    }
 
-                acc
-            });
+    fn part_two(&self, columns: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_second(columns).into())
+    }
+}
+
+fn solve_first(columns: &(Vec<i64>, Vec<i64>)) -> i64 {
+    let mut columns = columns.clone();
 
     columns.0.sort();
     columns.1.sort();
@@ -38,26 +59,23 @@ fn solve_first(input: &str) -> i64 {
     sum
 }
 
-fn solve_second(input: &str) -> usize {
-    let columns =
-        input
-            .lines()
-            .filter(|l| !l.trim().is_empty())
-            .fold((vec![], vec![]), |mut acc, l| {
-                let nums: Vec<_> = l
-                    .split_whitespace()
-                    .map(|s| s.parse::<usize>().expect("This must be a number"))
-                    .collect();
-
-                acc.0.push(nums[0]);
-                acc.1.push(nums[1]);
-
-                acc
-            });
-
+fn solve_second(columns: &(Vec<i64>, Vec<i64>)) -> i64 {
     columns
         .0
         .iter()
-        .map(|n| n * columns.1.iter().filter(|n2| n2 == &n).count())
+        .map(|n| n * columns.1.iter().filter(|n2| n2 == &n).count() as i64)
         .sum()
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!("3   4\n", "4   3\n", "2   5\n", "1   3\n", "3   9\n", "3   3\n");
+
+aoc_common::examples! {
+    solution: Day01,
+    part_one: {
+        EXAMPLE => "11",
+    },
+    part_two: {
+        EXAMPLE => "31",
+    },
+}
@@ -1,30 +1,46 @@
-use std::{fs, path::PathBuf};
+use aoc_common::{Answer, Solution, SolveError};
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+pub struct Day02;
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+aoc_common::register!(Day02);
 
-    (first.to_string(), second.to_string())
-}
+impl Solution for Day02 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 2;
+    const TITLE: &'static str = "Red-Nosed Reports";
 
-fn solve_first(input: &str) -> usize {
-    input
-        .lines()
-        .filter(|l| !l.trim().is_empty())
-        .filter(|l| {
-            let levels: Vec<_> = l
-                .split_whitespace()
-                .map(|s| s.parse::<i64>().expect("Levels must be numbers"))
-                .collect();
+    type Input = Vec<Vec<i64>>;
 
-            is_safe(&levels)
-        })
-        .count()
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        input
+            .lines()
+            .enumerate()
+            .filter(|(_, l)| !l.trim().is_empty())
+            .map(|(i, l)| {
+                l.split_whitespace()
+                    .map(|s| {
+                        s.parse::<i64>()
+                            .map_err(|_| SolveError::at_token(i, l, s, "Levels must be numbers"))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    fn part_one(&self, reports: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(reports).into())
+    }
+
+    fn part_two(&self, reports: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_second(reports).into())
+    }
 }
 
-fn is_safe(levels: &Vec<i64>) -> bool {
+fn solve_first(reports: &[Vec<i64>]) -> usize {
+    reports.iter().filter(|levels| is_safe(levels)).count()
+}
+
+fn is_safe(levels: &[i64]) -> bool {
     let all_decreasing = levels.windows(2).all(|w| {
         let diff = w[0] - w[1];
         diff > 0 && diff <= 3
@@ -37,22 +53,16 @@ fn is_safe(levels: &Vec<i64>) -> bool {
     all_decreasing || all_increasing
 }
 
-fn solve_second(input: &str) -> usize {
-    input
-        .lines()
-        .filter(|l| !l.trim().is_empty())
-        .filter(|l| {
-            let levels: Vec<_> = l
-                .split_whitespace()
-                .map(|s| s.parse::<i64>().expect("Levels must be numbers"))
-                .collect();
-
-            if is_safe(&levels) {
+fn solve_second(reports: &[Vec<i64>]) -> usize {
+    reports
+        .iter()
+        .filter(|levels| {
+            if is_safe(levels) {
                 return true;
             }
 
             for (i, _) in levels.iter().enumerate() {
-                let damped_levels = levels
+                let damped_levels: Vec<_> = levels
                     .iter()
                     .enumerate()
                     .filter_map(|(idx, l)| if idx == i { None } else { Some(*l) })
@@ -66,3 +76,23 @@ fn solve_second(input: &str) -> usize {
         })
         .count()
 }
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "7 6 4 2 1\n",
+    "1 2 7 8 9\n",
+    "9 7 6 2 1\n",
+    "1 3 2 4 5\n",
+    "8 6 4 4 1\n",
+    "1 3 6 7 9\n",
+);
+
+aoc_common::examples! {
+    solution: Day02,
+    part_one: {
+        EXAMPLE => "2",
+    },
+    part_two: {
+        EXAMPLE => "4",
+    },
+}
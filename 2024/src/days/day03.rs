@@ -1,14 +1,28 @@
-use std::{fs, path::PathBuf};
-
+use aoc_common::{Answer, Solution, SolveError};
 use regex::Regex;
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+pub struct Day03;
+
+aoc_common::register!(Day03);
+
+impl Solution for Day03 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 3;
+    const TITLE: &'static str = "Mull It Over";
+
+    type Input = String;
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.to_owned())
+    }
 
-    (first.to_string(), second.to_string())
+    fn part_one(&self, memory: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(memory).into())
+    }
+
+    fn part_two(&self, memory: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_second(memory).into())
+    }
 }
 
 fn solve_first(input: &str) -> i64 {
@@ -45,3 +59,13 @@ fn solve_second(input: &str) -> i64 {
         })
         .sum()
 }
+
+aoc_common::examples! {
+    solution: Day03,
+    part_one: {
+        "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))" => "161",
+    },
+    part_two: {
+        "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))" => "48",
+    },
+}
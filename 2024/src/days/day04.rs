@@ -1,112 +1,71 @@
-use regex::Regex;
-use std::fmt::Debug;
-use std::{fs, path::PathBuf};
+use aoc_common::{Answer, Grid, Point, Solution, SolveError};
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+pub struct Day04;
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+aoc_common::register!(Day04);
 
-    (first.to_string(), second.to_string())
-}
+impl Solution for Day04 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 4;
+    const TITLE: &'static str = "Ceres Search";
 
+    type Input = Grid<char>;
 
-fn solve_first(input: &str) -> usize {
-    let lines = input.lines().collect::<Vec<&str>>();
-    let horizontal: usize = lines
-        .iter()
-        .map(|line| count_search(line))
-        .sum();
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Grid::parse(input, Ok)
+    }
 
-    let lines_chars: Vec<_> = lines
-        .iter()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect();
+    fn part_one(&self, letters: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(letters).into())
+    }
 
-    let rotated = rotate_90deg(&lines_chars);
+    fn part_two(&self, letters: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_second(letters).into())
+    }
+}
 
-    let vertical: usize = rotated
-        .iter()
-        .map(|line| count_search(&line.iter().collect::<String>()))
+fn solve_first(letters: &Grid<char>) -> usize {
+    let horizontal: usize = letters
+        .rows()
+        .map(|row| count_search(&row.iter().collect::<String>()))
         .sum();
 
-    let diagonal_1: usize = get_diagonals(&lines_chars)
-        .iter()
-        .map(|line| count_search(&line.iter().collect::<String>()))
+    let vertical: usize = letters
+        .columns()
+        .map(|column| count_search(&column.collect::<String>()))
         .sum();
 
-    let diagonal_2: usize = get_diagonals(&rotated)
-        .iter()
-        .map(|line| count_search(&line.iter().collect::<String>()))
+    let diagonal_1: usize = letters
+        .diagonals()
+        .map(|diagonal| count_search(&diagonal.collect::<String>()))
         .sum();
 
-    println!(
-        "h: {}, v: {}, d1: {}, d2: {}",
-        horizontal, vertical, diagonal_1, diagonal_2
-    );
+    let diagonal_2: usize = letters
+        .anti_diagonals()
+        .map(|diagonal| count_search(&diagonal.collect::<String>()))
+        .sum();
 
     vertical + horizontal + diagonal_1 + diagonal_2
 }
 
-
-fn transpose<T: Clone>(v: &Vec<Vec<T>>) -> Vec<Vec<T>> {
-    (0..v[0].len())
-        .map(|i| v.iter().map(|row| row[i].clone()).collect::<Vec<T>>())
-        .collect()
-}
-
-fn rotate_90deg<T: Clone>(v: &Vec<Vec<T>>) -> Vec<Vec<T>> {
-    let mut transposed =  transpose(v);
-    transposed.iter_mut().for_each(|row| row.reverse());
-
-    transposed
-}
-
-
-fn get_diagonals<T: Clone + Debug>(v: &Vec<Vec<T>>) -> Vec<Vec<T>> {
-    let height = v.len();
-    let width = v[0].len();
-    let mut diagonals = vec![];
-    for d in 0..=(height + width - 2) {
-        let mut diagonal = vec![];
-
-        for y in 0..=d {
-            let x = d - y;
-            if y < height && x < width {
-                diagonal.push(v[y][x].clone());
-            }
-        }
-
-        diagonals.push(diagonal);
-    }
-    diagonals
-}
-
+/// Occurrences of `XMAS` forwards and backwards.
 fn count_search(s: &str) -> usize {
-    let xre = Regex::new(r"XMAS").unwrap();
-    let sre = Regex::new(r"SAMX").unwrap();
-
-    xre.captures_iter(s).count() + sre.captures_iter(s).count()
+    s.matches("XMAS").count() + s.matches("SAMX").count()
 }
 
-fn solve_second(input: &str) -> usize {
-    let lines = input.lines()
-        .map(|line| line.chars().collect::<Vec<char>>())
-        .collect::<Vec<Vec<char>>>();
-
+fn solve_second(letters: &Grid<char>) -> usize {
     let mut count = 0;
 
-    for y in 1..(lines.len() - 1) {
-        for x in 1..(lines[0].len() - 1) {
-            let center = lines[y][x];
+    for y in 1..letters.height().saturating_sub(1) {
+        for x in 1..letters.width().saturating_sub(1) {
+            let center = letters[Point::new(x, y)];
             if center != 'A' {
                 continue;
             }
-            let upper_left = lines[y-1][x-1];
-            let lower_right = lines[y+1][x+1];
-            let upper_right = lines[y-1][x+1];
-            let lower_left = lines[y+1][x-1];
+            let upper_left = letters[Point::new(x - 1, y - 1)];
+            let lower_right = letters[Point::new(x + 1, y + 1)];
+            let upper_right = letters[Point::new(x + 1, y - 1)];
+            let lower_left = letters[Point::new(x - 1, y + 1)];
 
             if is_ms_match(upper_left, lower_right) && is_ms_match(upper_right, lower_left) {
                 count += 1;
@@ -118,9 +77,29 @@ fn solve_second(input: &str) -> usize {
 }
 
 fn is_ms_match(a: char, b: char) -> bool {
-    match (a, b) {
-        ('M', 'S') => true,
-        ('S', 'M') => true,
-        _ => false,
-    }
+    matches!((a, b), ('M', 'S') | ('S', 'M'))
+}
+
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "MMMSXXMASM\n",
+    "MSAMXMSMSA\n",
+    "AMXSXMAAMM\n",
+    "MSAMASMSMX\n",
+    "XMASAMXAMM\n",
+    "XXAMMXXAMA\n",
+    "SMSMSASXSS\n",
+    "SAXAMASAAA\n",
+    "MAMMMXMMMM\n",
+    "MXMXAXMASX\n",
+);
+
+aoc_common::examples! {
+    solution: Day04,
+    part_one: {
+        EXAMPLE => "18",
+    },
+    part_two: {
+        EXAMPLE => "9",
+    },
 }
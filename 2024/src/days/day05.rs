@@ -1,17 +1,34 @@
+use aoc_common::{Answer, Solution, SolveError};
 use std::collections::{HashMap, HashSet};
-use std::{fs, path::PathBuf};
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+pub struct Day05;
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+aoc_common::register!(Day05);
 
-    (first.to_string(), second.to_string())
+type Manual = (Vec<(usize, usize)>, Vec<Vec<usize>>);
+
+impl Solution for Day05 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 5;
+    const TITLE: &'static str = "Print Queue";
+
+    type Input = Manual;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, manual: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(manual).into())
+    }
+
+    fn part_two(&self, _manual: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::NotImplemented)
+    }
 }
 
-fn solve_first(input: &str) -> usize {
-    let (rules_list, print_orders) = parse_input(input);
+fn solve_first(manual: &Manual) -> usize {
+    let (rules_list, print_orders) = manual;
 
     let rules = rules_list.iter().fold(HashMap::new(), |mut acc, (a, b)| {
         if !acc.contains_key(b) {
@@ -33,7 +50,7 @@ fn solve_first(input: &str) -> usize {
             .collect();
 
         let is_ordered = print_order.iter().enumerate().all(|(i, page)| {
-            let page_rules_opt = rules.get(&page);
+            let page_rules_opt = rules.get(page);
             if page_rules_opt.is_none() {
                 return true;
             }
@@ -51,38 +68,77 @@ fn solve_first(input: &str) -> usize {
 
         middle_numbers.push(print_order[print_order.len() / 2]);
     }
-    
+
     middle_numbers.iter().sum()
 }
 
-fn parse_input(input: &str) -> (Vec<(usize, usize)>, Vec<Vec<usize>>) {
+fn parse_input(input: &str) -> Result<Manual, SolveError> {
     let mut rules = vec![];
     let mut print_orders = vec![];
 
-    for line in input.lines() {
+    for (i, line) in input.lines().enumerate() {
         if line.is_empty() {
             continue;
         }
-        if let Some((aStr, bStr)) = line.split_once("|") {
-            let before = aStr.parse::<usize>().unwrap();
-            let after = bStr.parse::<usize>().unwrap();
+        let page = |s: &str| {
+            s.parse::<usize>()
+                .map_err(|_| SolveError::at_token(i, line, s, format!("invalid page '{s}'")))
+        };
+        if let Some((a_str, b_str)) = line.split_once("|") {
+            let before = page(a_str)?;
+            let after = page(b_str)?;
             rules.push((before, after));
             continue;
         }
 
         let order = line
             .split(",")
-            .map(|x| x.parse::<usize>().unwrap())
-            .collect::<Vec<usize>>();
+            .map(page)
+            .collect::<Result<Vec<usize>, _>>()?;
 
-        if order.len() > 0 {
+        if !order.is_empty() {
             print_orders.push(order);
         }
     }
 
-    (rules, print_orders)
+    Ok((rules, print_orders))
 }
 
-fn solve_second(_input: &str) -> i64 {
-    0
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "47|53\n",
+    "97|13\n",
+    "97|61\n",
+    "97|47\n",
+    "75|29\n",
+    "61|13\n",
+    "75|53\n",
+    "29|13\n",
+    "97|29\n",
+    "53|29\n",
+    "61|53\n",
+    "97|53\n",
+    "61|29\n",
+    "47|13\n",
+    "75|47\n",
+    "97|75\n",
+    "47|61\n",
+    "75|61\n",
+    "47|29\n",
+    "75|13\n",
+    "53|13\n",
+    "\n",
+    "75,47,61,53,29\n",
+    "97,61,53,29,13\n",
+    "75,29,13\n",
+    "75,97,47,61,53\n",
+    "61,13,29\n",
+    "97,13,75,29,47\n",
+);
+
+aoc_common::examples! {
+    solution: Day05,
+    part_one: {
+        EXAMPLE => "143",
+    },
 }
@@ -1,30 +1,49 @@
+use aoc_common::{Answer, Direction, Grid, Point, Solution, SolveError};
 use std::collections::HashSet;
-use std::{fs, path::PathBuf};
 
-pub fn solve(input_path: &PathBuf) -> (String, String) {
-    let input = fs::read_to_string(input_path).expect("Should have been able to read the file");
+pub struct Day06;
 
-    let first = solve_first(&input);
-    let second = solve_second(&input);
+aoc_common::register!(Day06);
 
-    (first.to_string(), second.to_string())
+type Lab = (Grid<Cell>, Point<usize>);
+
+impl Solution for Day06 {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = 6;
+    const TITLE: &'static str = "Guard Gallivant";
+
+    type Input = Lab;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        parse_input(input)
+    }
+
+    fn part_one(&self, lab: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(solve_first(lab)?.into())
+    }
+
+    fn part_two(&self, _lab: &Self::Input) -> Result<Answer, SolveError> {
+        Ok(Answer::NotImplemented)
+    }
 }
 
-fn solve_first(input: &str) -> usize {
-    let (cells, guard_start) = parse_input(input);
+fn solve_first(lab: &Lab) -> Result<usize, SolveError> {
+    let (cells, guard_start) = lab;
 
-    let mut visited: HashSet<(i64, i64)> = HashSet::new();
-    let mut guard_direction = GuardDirection::Up;
-    let mut guard_pos = guard_start;
+    let mut visited: HashSet<Point<usize>> = HashSet::new();
+    let mut states: HashSet<(Point<usize>, Direction)> = HashSet::new();
+    let mut guard_direction = Direction::Up;
+    let mut guard_pos = *guard_start;
 
-    while guard_pos.0 >= 0
-        && guard_pos.0 < cells[0].len() as i64
-        && guard_pos.1 >= 0
-        && guard_pos.1 < cells.len() as i64
-    {
+    loop {
         visited.insert(guard_pos);
+        if !states.insert((guard_pos, guard_direction)) {
+            return Err(SolveError::new(format!(
+                "the guard walks in a loop through {guard_pos} and never leaves the lab"
+            )));
+        }
 
-        let next_pos = find_next_pos(guard_pos, guard_direction, &cells);
+        let next_pos = find_next_pos(guard_pos, guard_direction, cells);
 
         match next_pos {
             None => break,
@@ -35,84 +54,77 @@ fn solve_first(input: &str) -> usize {
         };
     }
 
-    visited.len()
+    Ok(visited.len())
 }
 
+/// Position and direction of the guard after the next move, which is either a step forward or
+/// a turn to the right, or `None` once the guard leaves the lab.
 fn find_next_pos(
-    current_pos: (i64, i64),
-    current_direction: GuardDirection,
-    map: &Vec<Vec<Cell>>,
-) -> Option<((i64, i64), GuardDirection)> {
-    let new_pos = match current_direction {
-        GuardDirection::Up => (current_pos.0, current_pos.1 - 1),
-        GuardDirection::Down => (current_pos.0, current_pos.1 + 1),
-        GuardDirection::Left => (current_pos.0 - 1, current_pos.1),
-        GuardDirection::Right => (current_pos.0 + 1, current_pos.1),
-    };
-
-    if new_pos.0 < 0
-        || new_pos.0 as usize >= map[0].len()
-        || new_pos.1 < 0
-        || new_pos.1 as usize >= map.len()
-    {
-        return None;
-    }
+    current_pos: Point<usize>,
+    current_direction: Direction,
+    map: &Grid<Cell>,
+) -> Option<(Point<usize>, Direction)> {
+    let new_pos = map.step(current_pos, current_direction)?;
 
-    match &map[new_pos.1 as usize][new_pos.0 as usize] {
+    match &map[new_pos] {
         Cell::Empty => Some((new_pos, current_direction)),
-        Cell::Obstacle => find_next_pos(
-            current_pos,
-            match current_direction {
-                GuardDirection::Up => GuardDirection::Right,
-                GuardDirection::Right => GuardDirection::Down,
-                GuardDirection::Down => GuardDirection::Left,
-                GuardDirection::Left => GuardDirection::Up,
-            },
-            map,
-        ),
+        Cell::Obstacle => Some((current_pos, current_direction.turn_right())),
     }
 }
 
-#[derive(Clone, Copy)]
-enum GuardDirection {
-    Up,
-    Down,
-    Left,
-    Right,
-}
-
-enum Cell {
+pub enum Cell {
     Empty,
     Obstacle,
 }
 
-fn parse_input(input: &str) -> (Vec<Vec<Cell>>, (i64, i64)) {
-    let mut guard = None;
-    for (y, line) in input.lines().filter(|l| !l.is_empty()).enumerate() {
-        for (x, c) in line.chars().enumerate() {
-            if c == '^' {
-                guard = Some((x as i64, y as i64));
-                break;
-            }
-        }
-    }
+fn parse_input(input: &str) -> Result<Lab, SolveError> {
+    let map = Grid::parse(input, |c| match c {
+        '.' | '^' | '#' => Ok(c),
+        _ => Err(format!("Unexpected char {}", c)),
+    })?;
+
+    let guard = map
+        .position(|&c| c == '^')
+        .ok_or_else(|| SolveError::new("there must be a guard marked with '^'"))?;
+    let cells = map.map(|&c| match c {
+        '#' => Cell::Obstacle,
+        _ => Cell::Empty,
+    });
+
+    Ok((cells, guard))
+}
 
-    let cells = input
-        .lines()
-        .map(|line| {
-            line.chars()
-                .map(|c| match c {
-                    '.' | '^' => Cell::Empty,
-                    '#' => Cell::Obstacle,
-                    _ => panic!("Unexpected char {}", c),
-                })
-                .collect::<Vec<_>>()
-        })
-        .collect::<Vec<_>>();
-
-    (cells, guard.unwrap())
+#[cfg(test)]
+const EXAMPLE: &str = concat!(
+    "....#.....\n",
+    ".........#\n",
+    "..........\n",
+    "..#.......\n",
+    ".......#..\n",
+    "..........\n",
+    ".#..^.....\n",
+    "........#.\n",
+    "#.........\n",
+    "......#...\n",
+);
+
+aoc_common::examples! {
+    solution: Day06,
+    part_one: {
+        EXAMPLE => "41",
+    },
 }
 
-fn solve_second(_input: &str) -> i64 {
-    0
+#[test]
+fn test_guard_never_leaves() {
+    let solve = |input| solve_first(&parse_input(input).unwrap()).unwrap_err();
+
+    assert_eq!(
+        solve(".#.\n#^#\n.#.\n"),
+        SolveError::new("the guard walks in a loop through 1,1 and never leaves the lab")
+    );
+    assert_eq!(
+        solve(".#..\n...#\n#^..\n..#.\n"),
+        SolveError::new("the guard walks in a loop through 1,2 and never leaves the lab")
+    );
 }
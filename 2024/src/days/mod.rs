@@ -1,6 +1,4 @@
-use std::path::PathBuf;
-
-use phf::phf_map;
+use aoc_common::Puzzle;
 
 mod day01;
 mod day02;
@@ -9,21 +7,9 @@ mod day04;
 mod day05;
 mod day06;
 
-type DayFn = fn(&PathBuf) -> (String, String);
-
-static DAY_REGISTRY: phf::Map<u8, DayFn> = phf_map! {
-    1u8 => day01::solve,
-    2u8 => day02::solve,
-    3u8 => day03::solve,
-    4u8 => day04::solve,
-    5u8 => day05::solve,
-    6u8 => day06::solve,
-};
-
-pub fn solve_day(day: &u8, input_path: &PathBuf) -> (String, String) {
-    let solve = DAY_REGISTRY
-        .get(day)
-        .expect("solution for given day is not implemented");
+pub const YEAR: u16 = 2024;
 
-    solve(input_path)
+/// Solutions of all days of the year ordered by day, every day registers itself in its module.
+pub fn days() -> Vec<&'static dyn Puzzle> {
+    aoc_common::registered(YEAR)
 }
@@ -0,0 +1,50 @@
+//! Benchmarks parsing and both parts of every registered day that has an input file in the
+//! data directory of its year.
+
+use std::{fs, path::Path};
+
+use aoc::years;
+use aoc_common::{Params, Part};
+use criterion::{criterion_group, criterion_main, Criterion};
+
+/// Parts that take too long to be sampled, they are still run once by `aoc --all`.
+///
+/// Part two of 2023 day 5 tries every seed of its ranges, which takes minutes.
+const SKIPPED: &[(u16, u8, Part)] = &[(2023, 5, Part::Two)];
+
+fn days(c: &mut Criterion) {
+    let workspace = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+    let params = Params::new();
+
+    for (year, days) in years::years() {
+        for puzzle in days {
+            let day = puzzle.day();
+            let path = workspace.join(format!("{year}/data/day{day}.txt"));
+            let Ok(input) = fs::read_to_string(&path) else {
+                continue;
+            };
+            let Ok(parsed) = puzzle.parse_input(&input, &params) else {
+                eprintln!("skipping {year} day {day}, its input cannot be parsed");
+                continue;
+            };
+
+            let mut group = c.benchmark_group(format!("{year} day {day}"));
+            group.bench_function("parse", |b| b.iter(|| puzzle.parse_input(&input, &params)));
+            for (part, name) in [(Part::One, "part one"), (Part::Two, "part two")] {
+                if SKIPPED.contains(&(year, day, part)) {
+                    continue;
+                }
+                group.bench_function(name, |b| b.iter(|| parsed.solve(part)));
+            }
+            group.finish();
+        }
+    }
+}
+
+criterion_group! {
+    name = benches;
+    // some days take seconds, so fewer samples keep the whole suite reasonably fast
+    config = Criterion::default().sample_size(10);
+    targets = days
+}
+criterion_main!(benches);
@@ -0,0 +1,195 @@
+use std::{collections::BTreeMap, fmt::Write, fs, io, path::Path};
+
+use aoc_common::{Answer, Part};
+use serde::Serialize;
+
+/// Result of comparing an answer with the accepted one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Check {
+    Ok,
+    /// The part is a stub, so there is nothing to compare.
+    NotImplemented,
+    Missing,
+    Mismatch,
+}
+
+impl Check {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Check::Ok => "ok",
+            Check::NotImplemented => "not implemented",
+            Check::Missing => "missing",
+            Check::Mismatch => "mismatch",
+        }
+    }
+
+    /// Whether the run may succeed, stubs have nothing to compare so they do not fail it.
+    pub fn passed(self) -> bool {
+        matches!(self, Check::Ok | Check::NotImplemented)
+    }
+}
+
+/// Accepted answers of a year, stored as `{day} {part} {answer}` lines in a text file.
+///
+/// Empty lines and lines starting with `#` are ignored.
+#[derive(Debug, Default)]
+pub struct KnownAnswers {
+    answers: BTreeMap<(u8, Part), String>,
+}
+
+impl KnownAnswers {
+    /// Reads the answers from `path`, a missing file has no answers.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = match fs::read_to_string(path) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(format!("could not read {}: {err}", path.display())),
+        };
+
+        let mut answers = BTreeMap::new();
+        for (i, line) in content.lines().enumerate() {
+            if line.trim().is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let invalid = || {
+                format!(
+                    "{}:{}: expected '<day> <part> <answer>'",
+                    path.display(),
+                    i + 1
+                )
+            };
+            let mut fields = line.splitn(3, ' ');
+            let day = fields
+                .next()
+                .and_then(|d| d.parse::<u8>().ok())
+                .ok_or_else(invalid)?;
+            let part = fields
+                .next()
+                .and_then(|p| p.parse::<u8>().ok())
+                .and_then(Part::from_number)
+                .ok_or_else(invalid)?;
+            let answer = fields.next().ok_or_else(invalid)?;
+
+            answers.insert((day, part), answer.to_string());
+        }
+
+        Ok(KnownAnswers { answers })
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let mut content = String::from("# day part answer\n");
+        for ((day, part), answer) in &self.answers {
+            writeln!(content, "{day} {} {answer}", part.number()).unwrap();
+        }
+
+        fs::write(path, content).map_err(|err| format!("could not write {}: {err}", path.display()))
+    }
+
+    pub fn get(&self, day: u8, part: Part) -> Option<&str> {
+        self.answers.get(&(day, part)).map(String::as_str)
+    }
+
+    /// Compares the answer with the accepted one, pictures are compared in their
+    /// [single line form](Answer::to_line).
+    pub fn check(&self, day: u8, part: Part, answer: &Answer) -> Check {
+        if !answer.is_implemented() {
+            return Check::NotImplemented;
+        }
+        match self.get(day, part) {
+            Some(expected) if expected == answer.to_line() => Check::Ok,
+            Some(_) => Check::Mismatch,
+            None => Check::Missing,
+        }
+    }
+
+    pub fn insert(&mut self, day: u8, part: Part, answer: String) {
+        self.answers.insert((day, part), answer);
+    }
+
+    pub fn remove(&mut self, day: u8, part: Part) {
+        self.answers.remove(&(day, part));
+    }
+}
+
+#[test]
+fn test_load_skips_comments_and_blank_lines() {
+    let path = crate::temp_path("answers-comments.txt");
+    fs::write(
+        &path,
+        "# day part answer\n\n1 1 42\n  \n# 1 2 7\n3 2 two words\n",
+    )
+    .unwrap();
+
+    let known = KnownAnswers::load(&path);
+    fs::remove_file(&path).unwrap();
+
+    let known = known.unwrap();
+    assert_eq!(known.get(1, Part::One), Some("42"));
+    assert_eq!(known.get(1, Part::Two), None);
+    assert_eq!(known.get(3, Part::Two), Some("two words"));
+}
+
+#[test]
+fn test_load_reports_malformed_line() {
+    let path = crate::temp_path("answers-malformed.txt");
+    fs::write(&path, "# day part answer\n1 1 42\n1 3 7\n").unwrap();
+
+    let known = KnownAnswers::load(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        known.unwrap_err(),
+        format!("{}:3: expected '<day> <part> <answer>'", path.display())
+    );
+}
+
+#[test]
+fn test_save_and_load_round_trip() {
+    let path = crate::temp_path("answers-round-trip.txt");
+    let picture = Answer::picture(["#..#", "####"]);
+    let mut known = KnownAnswers::default();
+    known.insert(10, Part::Two, picture.to_line().into_owned());
+    known.insert(2, Part::One, String::from("1234"));
+
+    known.save(&path).unwrap();
+    let loaded = KnownAnswers::load(&path);
+    fs::remove_file(&path).unwrap();
+
+    let loaded = loaded.unwrap();
+    assert_eq!(loaded.answers, known.answers);
+    assert_eq!(loaded.check(10, Part::Two, &picture), Check::Ok);
+    assert_eq!(
+        loaded.check(2, Part::One, &Answer::Integer(1234)),
+        Check::Ok
+    );
+}
+
+#[test]
+fn test_check_statuses() {
+    let mut known = KnownAnswers::default();
+    known.insert(1, Part::One, String::from("42"));
+
+    let checks = [
+        known.check(1, Part::One, &Answer::Integer(42)),
+        known.check(1, Part::Two, &Answer::NotImplemented),
+    ];
+    assert_eq!(checks, [Check::Ok, Check::NotImplemented]);
+    assert_eq!(
+        crate::exit_code(checks.iter().all(|c| c.passed())),
+        std::process::ExitCode::SUCCESS
+    );
+
+    let checks = [
+        known.check(1, Part::One, &Answer::Integer(41)),
+        known.check(1, Part::Two, &Answer::Integer(7)),
+    ];
+    assert_eq!(checks, [Check::Mismatch, Check::Missing]);
+    for check in checks {
+        assert_eq!(
+            crate::exit_code(check.passed()),
+            std::process::ExitCode::FAILURE
+        );
+    }
+}
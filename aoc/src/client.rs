@@ -0,0 +1,262 @@
+use std::fmt;
+
+use aoc_common::Part;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// The website asks automated tools to identify themselves.
+const USER_AGENT: &str = "github.com/tobiaswatzek/adventofcode";
+
+/// Client of the Advent of Code website, authenticated with the session cookie of a login.
+pub struct Client {
+    agent: ureq::Agent,
+    base_url: String,
+    session: String,
+}
+
+/// How the website judged a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong without a hint whether it is too high or too low.
+    Incorrect,
+    /// Answers were submitted too recently, the message tells how long to wait.
+    TooRecent(String),
+    /// The part is locked or has already been solved.
+    WrongLevel,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "the answer is correct"),
+            Verdict::TooHigh => write!(f, "the answer is too high"),
+            Verdict::TooLow => write!(f, "the answer is too low"),
+            Verdict::Incorrect => write!(f, "the answer is not correct"),
+            Verdict::TooRecent(message) => write!(f, "{message}"),
+            Verdict::WrongLevel => write!(f, "the part is locked or has already been solved"),
+        }
+    }
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        }
+    }
+
+    /// Downloads the puzzle input of the logged in user.
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, String> {
+        let url = format!("{}/{year}/day/{day}/input", self.base_url);
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &self.cookie())
+            .call()
+            .map_err(|err| request_error(&url, err))?;
+
+        response
+            .into_string()
+            .map_err(|err| format!("could not read the response of {url}: {err}"))
+    }
+
+    /// Submits the answer of a part and returns how the website judged it.
+    pub fn submit(&self, year: u16, day: u8, part: Part, answer: &str) -> Result<Verdict, String> {
+        let url = format!("{}/{year}/day/{day}/answer", self.base_url);
+        let level = part.number().to_string();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &self.cookie())
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|err| request_error(&url, err))?;
+        let page = response
+            .into_string()
+            .map_err(|err| format!("could not read the response of {url}: {err}"))?;
+
+        parse_verdict(&page)
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+}
+
+fn request_error(url: &str, err: ureq::Error) -> String {
+    match err {
+        ureq::Error::Status(status, response) => {
+            let body = response.into_string().unwrap_or_default();
+            format!("{url} responded with {status}: {}", body.trim())
+        }
+        ureq::Error::Transport(transport) => format!("could not reach {url}: {transport}"),
+    }
+}
+
+/// Reads the verdict from the page the website responds to a submission with.
+///
+/// The message is the text of the page's `<article>`, which is matched by its wording.
+fn parse_verdict(page: &str) -> Result<Verdict, String> {
+    let message = article_text(page);
+
+    if message.contains("That's the right answer") {
+        Ok(Verdict::Correct)
+    } else if message.contains("your answer is too high") {
+        Ok(Verdict::TooHigh)
+    } else if message.contains("your answer is too low") {
+        Ok(Verdict::TooLow)
+    } else if message.contains("That's not the right answer") {
+        Ok(Verdict::Incorrect)
+    } else if message.contains("You gave an answer too recently") {
+        Ok(Verdict::TooRecent(message))
+    } else if message.contains("You don't seem to be solving the right level") {
+        Ok(Verdict::WrongLevel)
+    } else {
+        Err(format!("unexpected response to the submission: {message}"))
+    }
+}
+
+/// Text of the `<article>` of a page without any tags, or of the whole page if it has none.
+fn article_text(page: &str) -> String {
+    let article = page
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(page, |(article, _)| article);
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in article.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            c if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Request the stub server received.
+#[cfg(test)]
+struct Received {
+    method: String,
+    url: String,
+    cookie: String,
+    body: String,
+}
+
+/// Serves a single request with `body` on a local port and returns the base URL of the server.
+#[cfg(test)]
+fn stub_server(status: u16, body: &'static str) -> (String, std::thread::JoinHandle<Received>) {
+    let server = tiny_http::Server::http("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", server.server_addr());
+
+    let handle = std::thread::spawn(move || {
+        let mut request = server.recv().unwrap();
+        let cookie = request
+            .headers()
+            .iter()
+            .find(|h| h.field.equiv("Cookie"))
+            .map(|h| h.value.to_string())
+            .unwrap_or_default();
+        let mut content = String::new();
+        request.as_reader().read_to_string(&mut content).unwrap();
+        let received = Received {
+            method: request.method().to_string(),
+            url: request.url().to_string(),
+            cookie,
+            body: content,
+        };
+        let response = tiny_http::Response::from_string(body).with_status_code(status);
+        request.respond(response).unwrap();
+
+        received
+    });
+
+    (base_url, handle)
+}
+
+#[test]
+fn test_fetch_input() {
+    let (base_url, server) = stub_server(200, "1\n2\n3\n");
+
+    let input = Client::new(&base_url, "secret")
+        .fetch_input(2023, 7)
+        .unwrap();
+
+    assert_eq!(input, "1\n2\n3\n");
+    let received = server.join().unwrap();
+    assert_eq!(received.method, "GET");
+    assert_eq!(received.url, "/2023/day/7/input");
+    assert_eq!(received.cookie, "session=secret");
+}
+
+#[test]
+fn test_fetch_input_without_login() {
+    let (base_url, server) = stub_server(400, "Please log in to get your puzzle input.\n");
+
+    let err = Client::new(&base_url, "expired")
+        .fetch_input(2023, 7)
+        .unwrap_err();
+
+    assert!(err.ends_with("responded with 400: Please log in to get your puzzle input."));
+    server.join().unwrap();
+}
+
+#[test]
+fn test_submit() {
+    let (base_url, server) = stub_server(
+        200,
+        concat!(
+            "<html><body><main><article><p>That's not the right answer; ",
+            "your answer is too high.  If you're stuck, ...</p></article></main></body></html>",
+        ),
+    );
+
+    let verdict = Client::new(&base_url, "secret")
+        .submit(2022, 15, Part::Two, "10229191267339")
+        .unwrap();
+
+    assert_eq!(verdict, Verdict::TooHigh);
+    let received = server.join().unwrap();
+    assert_eq!(received.method, "POST");
+    assert_eq!(received.url, "/2022/day/15/answer");
+    assert_eq!(received.cookie, "session=secret");
+    assert_eq!(received.body, "level=2&answer=10229191267339");
+}
+
+#[test]
+fn test_parse_verdict() {
+    let verdicts = [
+        (
+            "<article><p>That's the right answer! You are <em>one gold star</em> closer.</p></article>",
+            Verdict::Correct,
+        ),
+        (
+            "<article><p>That's not the right answer; your answer is too low.</p></article>",
+            Verdict::TooLow,
+        ),
+        (
+            "<article><p>That's not the right answer. If you're stuck, ...</p></article>",
+            Verdict::Incorrect,
+        ),
+        (
+            "<article><p>You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 39s left to wait.</p></article>",
+            Verdict::TooRecent(String::from("You gave an answer too recently; you have to wait after submitting an answer before trying again. You have 39s left to wait.")),
+        ),
+        (
+            "<article><p>You don't seem to be solving the right level.  Did you already complete it?</p></article>",
+            Verdict::WrongLevel,
+        ),
+    ];
+
+    for (page, expected) in verdicts {
+        assert_eq!(parse_verdict(page), Ok(expected));
+    }
+    assert!(parse_verdict("<html>Maintenance</html>").is_err());
+}
@@ -0,0 +1,502 @@
+use std::{
+    fs::{self, OpenOptions},
+    path::Path,
+    process::Command,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use aoc_common::Part;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    table::{format_duration, Table},
+    Run,
+};
+
+/// File the timings of all runs are appended to, relative to the workspace root.
+pub const DEFAULT_PATH: &str = ".aoc-history.csv";
+
+/// How many of the latest commits are shown in the trend of a report.
+const TREND_LENGTH: usize = 5;
+
+/// What was timed in a run of a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Step {
+    Parse,
+    PartOne,
+    PartTwo,
+}
+
+impl Step {
+    fn as_str(self) -> &'static str {
+        match self {
+            Step::Parse => "parse",
+            Step::PartOne => "part one",
+            Step::PartTwo => "part two",
+        }
+    }
+}
+
+/// Build profile of the runner, debug builds are far slower than release builds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Profile {
+    Debug,
+    Release,
+}
+
+impl Profile {
+    /// Profile of the running binary.
+    fn current() -> Self {
+        if cfg!(debug_assertions) {
+            Profile::Debug
+        } else {
+            Profile::Release
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Profile::Debug => "debug",
+            Profile::Release => "release",
+        }
+    }
+}
+
+/// Whether a day ran alone or in parallel with others, which compete for the cores.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Mode {
+    Single,
+    Parallel,
+}
+
+impl Mode {
+    fn as_str(self) -> &'static str {
+        match self {
+            Mode::Single => "single",
+            Mode::Parallel => "parallel",
+        }
+    }
+}
+
+/// A single timing in the history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// Commit the solutions were built from, empty outside of a git repository.
+    pub commit: String,
+    pub year: u16,
+    pub day: u8,
+    pub step: Step,
+    pub time_ns: u64,
+    /// `None` for entries of histories written before the profile was recorded.
+    #[serde(default)]
+    pub profile: Option<Profile>,
+    /// `None` for entries of histories written before the mode was recorded.
+    #[serde(default)]
+    pub mode: Option<Mode>,
+}
+
+/// Columns of the history, in the order of the fields of [`Entry`].
+const HEADER: &str = "timestamp,commit,year,day,step,time_ns,profile,mode";
+
+/// Appends the timings of all successful runs to the history at `path`, `mode` tells how the
+/// days were run.
+pub fn append(path: &Path, runs: &[Run], mode: Mode) -> Result<(), String> {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs());
+    let commit = current_commit(Path::new(".")).unwrap_or_default();
+
+    let exists = fs::metadata(path).is_ok_and(|m| m.len() > 0);
+    if exists {
+        upgrade(path)?;
+    }
+    let file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .map_err(|err| format!("could not open {}: {err}", path.display()))?;
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(!exists)
+        .from_writer(file);
+
+    for run in runs {
+        let Ok(answers) = &run.result else {
+            continue;
+        };
+        let steps = [
+            (Step::Parse, Some(answers.parse_elapsed)),
+            (Step::PartOne, answers.get(Part::One).map(|a| a.elapsed)),
+            (Step::PartTwo, answers.get(Part::Two).map(|a| a.elapsed)),
+        ];
+        for (step, elapsed) in steps {
+            let Some(elapsed) = elapsed else {
+                continue;
+            };
+            let entry = Entry {
+                timestamp,
+                commit: commit.clone(),
+                year: run.puzzle.year(),
+                day: run.puzzle.day(),
+                step,
+                time_ns: elapsed.as_nanos() as u64,
+                profile: Some(Profile::current()),
+                mode: Some(mode),
+            };
+            writer
+                .serialize(entry)
+                .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+        }
+    }
+
+    writer
+        .flush()
+        .map_err(|err| format!("could not write {}: {err}", path.display()))
+}
+
+/// Rewrites a history that lacks columns of [`Entry`] with the current ones, which are left
+/// empty for the old entries.
+fn upgrade(path: &Path) -> Result<(), String> {
+    let content = fs::read_to_string(path)
+        .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+    if content.lines().next() == Some(HEADER) {
+        return Ok(());
+    }
+
+    let entries = load(path)?;
+    let mut writer = csv::Writer::from_path(path)
+        .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+    for entry in entries {
+        writer
+            .serialize(entry)
+            .map_err(|err| format!("could not write {}: {err}", path.display()))?;
+    }
+
+    writer
+        .flush()
+        .map_err(|err| format!("could not write {}: {err}", path.display()))
+}
+
+/// Short hash of the commit checked out in `dir`, marked as dirty if there are uncommitted
+/// changes.
+fn current_commit(dir: &Path) -> Option<String> {
+    let git = |args: &[&str]| {
+        let output = Command::new("git")
+            .arg("-C")
+            .arg(dir)
+            .args(args)
+            .output()
+            .ok()?;
+        output
+            .status
+            .success()
+            .then(|| String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+
+    let commit = git(&["rev-parse", "--short", "HEAD"])?;
+    let dirty = !git(&["status", "--porcelain", "--untracked-files=no"])?.is_empty();
+
+    Some(if dirty {
+        format!("{commit}-dirty")
+    } else {
+        commit
+    })
+}
+
+pub fn load(path: &Path) -> Result<Vec<Entry>, String> {
+    let mut reader = csv::Reader::from_path(path)
+        .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+
+    reader
+        .deserialize()
+        .collect::<Result<_, _>>()
+        .map_err(|err| format!("could not read {}: {err}", path.display()))
+}
+
+/// Median times of a step per commit, in the order the commits were first run.
+///
+/// Only timings of the same build profile and mode are compared.
+#[derive(Debug, PartialEq)]
+pub struct Trend {
+    pub year: u16,
+    pub day: u8,
+    pub step: Step,
+    pub profile: Option<Profile>,
+    pub mode: Option<Mode>,
+    pub medians: Vec<(String, Duration)>,
+    /// Number of runs of the latest commit.
+    pub runs: usize,
+}
+
+impl Trend {
+    /// Relative change of the latest median compared to the commit before it, e.g. `0.25`
+    /// if it got 25% slower.
+    pub fn change(&self) -> Option<f64> {
+        let [.., (_, before), (_, latest)] = self.medians.as_slice() else {
+            return None;
+        };
+
+        Some(latest.as_secs_f64() / before.as_secs_f64() - 1.0)
+    }
+
+    /// Whether the latest median is slower than the one before by more than `threshold`.
+    pub fn regressed(&self, threshold: f64) -> bool {
+        self.change().is_some_and(|change| change > threshold)
+    }
+}
+
+/// Groups the entries by step, build profile, mode and commit, ordered by year, day, step,
+/// profile and mode.
+pub fn trends(entries: &[Entry]) -> Vec<Trend> {
+    let key = |e: &Entry| (e.year, e.day, e.step, e.profile, e.mode);
+    let mut keys: Vec<_> = entries.iter().map(key).collect();
+    keys.sort_unstable();
+    keys.dedup();
+
+    keys.into_iter()
+        .map(|(year, day, step, profile, mode)| {
+            let entries: Vec<&Entry> = entries
+                .iter()
+                .filter(|e| key(e) == (year, day, step, profile, mode))
+                .collect();
+            let mut commits: Vec<&str> = vec![];
+            for entry in &entries {
+                if !commits.contains(&entry.commit.as_str()) {
+                    commits.push(&entry.commit);
+                }
+            }
+
+            let times = |commit: &str| -> Vec<u64> {
+                entries
+                    .iter()
+                    .filter(|e| e.commit == commit)
+                    .map(|e| e.time_ns)
+                    .collect()
+            };
+            let medians = commits
+                .iter()
+                .map(|c| (c.to_string(), Duration::from_nanos(median(times(c)))))
+                .collect();
+
+            Trend {
+                year,
+                day,
+                step,
+                profile,
+                mode,
+                medians,
+                runs: commits.last().map_or(0, |c| times(c).len()),
+            }
+        })
+        .collect()
+}
+
+fn median(mut times: Vec<u64>) -> u64 {
+    times.sort_unstable();
+    match times.len() {
+        0 => 0,
+        n if n % 2 == 0 => (times[n / 2 - 1] + times[n / 2]) / 2,
+        n => times[n / 2],
+    }
+}
+
+/// Table of the trends, steps that regressed beyond `threshold` are flagged.
+pub fn report(trends: &[Trend], threshold: f64) -> Table {
+    let mut table = Table::new(&[
+        "Year",
+        "Day",
+        "Step",
+        "Profile",
+        "Mode",
+        "Runs",
+        "Trend",
+        "Change",
+        "Regressed",
+    ]);
+    for trend in trends {
+        let skip = trend.medians.len().saturating_sub(TREND_LENGTH);
+        let medians: Vec<String> = trend.medians[skip..]
+            .iter()
+            .map(|(_, median)| format_duration(*median))
+            .collect();
+
+        table.push(vec![
+            trend.year.to_string(),
+            trend.day.to_string(),
+            trend.step.as_str().to_string(),
+            trend.profile.map_or("unknown", Profile::as_str).to_string(),
+            trend.mode.map_or("unknown", Mode::as_str).to_string(),
+            trend.runs.to_string(),
+            medians.join(" -> "),
+            trend
+                .change()
+                .map(|change| format!("{:+.1}%", change * 100.0))
+                .unwrap_or_default(),
+            if trend.regressed(threshold) {
+                String::from("yes")
+            } else {
+                String::new()
+            },
+        ]);
+    }
+
+    table
+}
+
+#[cfg(test)]
+fn entry(commit: &str, step: Step, time_ns: u64) -> Entry {
+    Entry {
+        timestamp: 0,
+        commit: commit.to_string(),
+        year: 2023,
+        day: 5,
+        step,
+        time_ns,
+        profile: Some(Profile::Release),
+        mode: Some(Mode::Single),
+    }
+}
+
+#[test]
+fn test_trends_use_median_per_commit() {
+    let entries = [
+        entry("a", Step::PartTwo, 100),
+        entry("a", Step::PartTwo, 900),
+        entry("a", Step::PartTwo, 110),
+        entry("b", Step::PartTwo, 130),
+        entry("b", Step::PartTwo, 140),
+        entry("a", Step::Parse, 10),
+    ];
+
+    let trends = trends(&entries);
+
+    assert_eq!(trends.len(), 2);
+    assert_eq!(trends[0].step, Step::Parse);
+    assert_eq!(trends[0].change(), None);
+    assert_eq!(
+        trends[1].medians,
+        vec![
+            (String::from("a"), Duration::from_nanos(110)),
+            (String::from("b"), Duration::from_nanos(135)),
+        ]
+    );
+    assert_eq!(trends[1].runs, 2);
+    assert!(trends[1].regressed(0.2));
+    assert!(!trends[1].regressed(0.25));
+}
+
+#[test]
+fn test_trends_compare_like_with_like() {
+    let entries = [
+        entry("a", Step::PartOne, 100),
+        entry("b", Step::PartOne, 100),
+        Entry {
+            mode: Some(Mode::Parallel),
+            ..entry("b", Step::PartOne, 300)
+        },
+        Entry {
+            profile: Some(Profile::Debug),
+            ..entry("b", Step::PartOne, 5000)
+        },
+    ];
+
+    let trends = trends(&entries);
+
+    let keys: Vec<_> = trends.iter().map(|t| (t.profile, t.mode)).collect();
+    assert_eq!(
+        keys,
+        [
+            (Some(Profile::Debug), Some(Mode::Single)),
+            (Some(Profile::Release), Some(Mode::Single)),
+            (Some(Profile::Release), Some(Mode::Parallel)),
+        ]
+    );
+    assert_eq!(trends[1].change(), Some(0.0));
+    assert_eq!(trends[2].change(), None);
+}
+
+#[test]
+fn test_report_flags_regressions_beyond_threshold() {
+    let entries = [
+        entry("a", Step::Parse, 100),
+        entry("b", Step::Parse, 130),
+        entry("a", Step::PartOne, 100),
+        entry("b", Step::PartOne, 110),
+    ];
+    let trends = trends(&entries);
+
+    let report = report(&trends, 0.2).to_string();
+
+    // change and regressed columns of each row
+    let flags: Vec<Vec<&str>> = report
+        .lines()
+        .skip(2)
+        .map(|row| row.split('|').map(str::trim).skip(7).collect())
+        .collect();
+    assert_eq!(flags, [vec!["+30.0%", "yes"], vec!["+10.0%", ""]]);
+}
+
+#[test]
+fn test_current_commit_is_dirty_with_uncommitted_changes() {
+    let dir = crate::temp_path("history-commit");
+    fs::create_dir_all(&dir).unwrap();
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .arg("-C")
+            .arg(&dir)
+            .args(["-c", "user.name=aoc", "-c", "user.email=aoc@localhost"])
+            .args(args)
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {args:?} failed");
+    };
+    git(&["init", "--quiet"]);
+    fs::write(dir.join("day1.rs"), "fn main() {}\n").unwrap();
+    git(&["add", "day1.rs"]);
+    git(&["commit", "--quiet", "-m", "Add day 1"]);
+
+    // untracked files are not part of the build
+    fs::write(dir.join("day2.rs"), "").unwrap();
+    let clean = current_commit(&dir);
+    fs::write(dir.join("day1.rs"), "fn main() { todo!() }\n").unwrap();
+    let dirty = current_commit(&dir);
+    fs::remove_dir_all(&dir).unwrap();
+
+    let clean = clean.unwrap();
+    assert!(!clean.is_empty() && !clean.ends_with("-dirty"));
+    assert_eq!(dirty, Some(format!("{clean}-dirty")));
+}
+
+#[test]
+fn test_upgrade_keeps_old_entries() {
+    let path = crate::temp_path("history-upgrade.csv");
+    fs::write(
+        &path,
+        "timestamp,commit,year,day,step,time_ns\n1,a,2023,5,parse,100\n",
+    )
+    .unwrap();
+
+    upgrade(&path).unwrap();
+    let content = fs::read_to_string(&path);
+    let entries = load(&path);
+    fs::remove_file(&path).unwrap();
+
+    assert_eq!(
+        content.unwrap(),
+        format!("{HEADER}\n1,a,2023,5,parse,100,,\n")
+    );
+    assert_eq!(
+        entries.unwrap(),
+        [Entry {
+            timestamp: 1,
+            profile: None,
+            mode: None,
+            ..entry("a", Step::Parse, 100)
+        }]
+    );
+}
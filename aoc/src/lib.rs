@@ -0,0 +1,3 @@
+//! Parts of the runner that the benchmarks share with the `aoc` binary.
+
+pub mod years;
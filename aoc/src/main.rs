@@ -0,0 +1,898 @@
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    fs,
+    io::{self, Write},
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::ExitCode,
+};
+
+use answers::{Check, KnownAnswers};
+use aoc::years;
+use aoc_common::{Answer, Answers, Error, Params, Part, Puzzle};
+use clap::{Parser, Subcommand};
+use memory::MemoryUsage;
+use output::Format;
+use rayon::prelude::*;
+
+#[global_allocator]
+static ALLOCATOR: memory::CountingAllocator = memory::CountingAllocator;
+
+mod answers;
+mod client;
+mod history;
+mod memory;
+mod output;
+mod scaffold;
+mod table;
+mod watch;
+
+#[derive(Parser, Debug)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+struct Cli {
+    #[command(subcommand)]
+    command: Option<Command>,
+    #[command(flatten)]
+    args: Args,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Creates the solution template of a new day, registers it and adds an empty input file.
+    New {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// Title of the puzzle as shown on the website, defaults to `Day {day}`.
+        #[arg(long)]
+        title: Option<String>,
+        /// Directory to create the `day{N}.txt` input in, defaults to `{year}/data`.
+        #[arg(long)]
+        data_dir: Option<PathBuf>,
+    },
+    /// Downloads the input of a day into the data directory, unless it is there already.
+    Fetch {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        /// Directory to store the `day{N}.txt` input in, defaults to `{year}/data`.
+        #[arg(long)]
+        data_dir: Option<PathBuf>,
+        /// Downloads the input even if it has been downloaded before.
+        #[arg(long)]
+        force: bool,
+        #[command(flatten)]
+        server: Server,
+    },
+    /// Submits the answer of a part and records it as the accepted one if it is correct.
+    Submit {
+        #[arg(long)]
+        year: u16,
+        #[arg(long)]
+        day: u8,
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Answer to submit, defaults to the one the solution finds for the input of the day.
+        #[arg(long)]
+        answer: Option<String>,
+        /// Directory containing the `day{N}.txt` inputs, defaults to `{year}/data`.
+        #[arg(long, conflicts_with = "answer")]
+        data_dir: Option<PathBuf>,
+        /// File containing the accepted answers, defaults to `{year}/answers.txt`.
+        #[arg(long)]
+        answers: Option<PathBuf>,
+        #[command(flatten)]
+        server: Server,
+    },
+    /// Shows how the median times changed over the commits and flags regressions.
+    Report {
+        /// File the timings of the runs were appended to.
+        #[arg(long, default_value = history::DEFAULT_PATH)]
+        history: PathBuf,
+        /// Percentage the median of the latest commit may be slower than the one before.
+        #[arg(long, default_value_t = 10.0)]
+        threshold: f64,
+        #[arg(long)]
+        year: Option<u16>,
+        #[arg(long, requires = "year")]
+        day: Option<u8>,
+    },
+}
+
+/// Where and as whom the website is accessed.
+#[derive(clap::Args, Debug)]
+struct Server {
+    /// Value of the `session` cookie of a login on the website, only needed for requests.
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// URL of the website, e.g. of a local server for testing.
+    #[arg(long, env = "AOC_BASE_URL", default_value = client::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+impl Server {
+    /// Client for the website, which fails without a session.
+    fn client(&self) -> Result<client::Client, String> {
+        let session = self
+            .session
+            .as_deref()
+            .ok_or("a session is required, pass --session or set AOC_SESSION")?;
+
+        Ok(client::Client::new(&self.base_url, session))
+    }
+}
+
+#[derive(clap::Args, Debug)]
+struct Args {
+    /// Year of the puzzles, all years are run if it is omitted together with `--all`.
+    #[arg(long, required_unless_present = "all")]
+    year: Option<u16>,
+    #[arg(long, required_unless_present = "all", conflicts_with = "all")]
+    day: Option<u8>,
+    /// Runs every registered day that has an input file and prints a summary table.
+    #[arg(long)]
+    all: bool,
+    /// Directory containing the `day{N}.txt` inputs, defaults to `{year}/data`.
+    #[arg(long, requires = "year")]
+    data_dir: Option<PathBuf>,
+    /// File to read the input of the day from instead of the data directory, `-` reads stdin.
+    ///
+    /// The timings of such runs are not appended to the history.
+    #[arg(long, conflicts_with_all = ["all", "data_dir"])]
+    input: Option<PathBuf>,
+    /// Compares the answers with the accepted ones and fails if any differ or are missing.
+    #[arg(long, conflicts_with = "record")]
+    check: bool,
+    /// Stores the answers as the accepted ones.
+    #[arg(long)]
+    record: bool,
+    /// File containing the accepted answers, defaults to `{year}/answers.txt`.
+    #[arg(long, requires = "year")]
+    answers: Option<PathBuf>,
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// Solves only the given part instead of both.
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+    /// Overrides a parameter of the puzzle, e.g. `--param row=10` to solve an example.
+    ///
+    /// The timings of such runs are not appended to the history.
+    #[arg(
+        long = "param",
+        value_name = "NAME=VALUE",
+        value_parser = aoc_common::parse_assignment,
+        conflicts_with_all = ["all", "check", "record"]
+    )]
+    params: Vec<(String, i64)>,
+    /// File the timings of the run are appended to.
+    #[arg(long, default_value = history::DEFAULT_PATH)]
+    history: PathBuf,
+    /// Does not append the timings of the run to the history.
+    #[arg(long)]
+    no_history: bool,
+    /// Counts the allocations and the peak heap usage of every day and part, which solves the
+    /// days one after another.
+    #[arg(long, conflicts_with_all = ["watch", "format"])]
+    profile_mem: bool,
+    /// Solves the day again whenever its input file changes, until interrupted.
+    #[arg(long, conflicts_with_all = ["all", "check", "record", "format"])]
+    watch: bool,
+}
+
+/// Result of running the solution of a single day.
+pub struct Run {
+    pub puzzle: &'static dyn Puzzle,
+    /// The answers or a description of why the input could not be read or solved.
+    pub result: Result<Answers, String>,
+    /// Heap usage of the steps if it was profiled.
+    pub memory: Option<MemoryUsage>,
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Some(Command::New {
+            year,
+            day,
+            title,
+            data_dir,
+        }) => exit_code(new_day(
+            Path::new(""),
+            year,
+            day,
+            title.as_deref(),
+            data_dir,
+        )),
+        Some(Command::Fetch {
+            year,
+            day,
+            data_dir,
+            force,
+            server,
+        }) => {
+            let data_dir = data_dir.unwrap_or_else(|| default_data_dir(year));
+            exit_code(fetch(year, day, &data_dir, force, &server))
+        }
+        Some(Command::Submit {
+            year,
+            day,
+            part,
+            answer,
+            data_dir,
+            answers,
+            server,
+        }) => {
+            let data_dir = data_dir.unwrap_or_else(|| default_data_dir(year));
+            let answers = answers.unwrap_or_else(|| default_answers_path(year));
+            let part = Part::from_number(part).expect("clap only allows parts 1 and 2");
+            exit_code(submit(
+                year, day, part, answer, &data_dir, &answers, &server,
+            ))
+        }
+        Some(Command::Report {
+            history,
+            threshold,
+            year,
+            day,
+        }) => exit_code(report(&history, threshold, year, day)),
+        None if cli.args.watch => watch_day(&cli.args),
+        None => run_days(&cli.args),
+    }
+}
+
+/// Downloads the input of a day, an input that is not empty is kept unless `force` is set.
+fn fetch(year: u16, day: u8, data_dir: &Path, force: bool, server: &Server) -> bool {
+    let path = file_path(data_dir, &day);
+    if !force && fs::metadata(&path).is_ok_and(|m| m.len() > 0) {
+        println!("{} has already been downloaded", path.display());
+        return true;
+    }
+
+    let written = server
+        .client()
+        .and_then(|client| client.fetch_input(year, day))
+        .and_then(|input| {
+            fs::create_dir_all(data_dir)
+                .and_then(|()| fs::write(&path, input))
+                .map_err(|err| format!("could not write {}: {err}", path.display()))
+        });
+    match written {
+        Ok(()) => {
+            println!("Wrote {}", path.display());
+            true
+        }
+        Err(message) => {
+            eprintln!("error: {message}");
+            false
+        }
+    }
+}
+
+/// Submits the given answer or the one the solution finds and records it if it is correct.
+fn submit(
+    year: u16,
+    day: u8,
+    part: Part,
+    answer: Option<String>,
+    data_dir: &Path,
+    answers_path: &Path,
+    server: &Server,
+) -> bool {
+    let answer = match answer {
+        Some(answer) => answer,
+        None => match solve_part(year, day, part, data_dir) {
+            Ok(answer) => answer,
+            Err(message) => {
+                eprintln!("error: {message}");
+                return false;
+            }
+        },
+    };
+
+    let verdict = match server
+        .client()
+        .and_then(|client| client.submit(year, day, part, &answer))
+    {
+        Ok(verdict) => verdict,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return false;
+        }
+    };
+    println!("{year} day {day} {part}: {answer}, {verdict}");
+    if verdict != client::Verdict::Correct {
+        return false;
+    }
+
+    let recorded = KnownAnswers::load(answers_path).and_then(|mut known| {
+        known.insert(day, part, answer);
+        known.save(answers_path)
+    });
+    match recorded {
+        Ok(()) => {
+            println!("Recorded the answer in {}", answers_path.display());
+            true
+        }
+        Err(message) => {
+            eprintln!("error: {message}");
+            false
+        }
+    }
+}
+
+/// Solves a single part for the input in the data directory.
+fn solve_part(year: u16, day: u8, part: Part, data_dir: &Path) -> Result<String, String> {
+    let days = years::find_year(year).ok_or(format!("there are no solutions for {year}"))?;
+    let puzzle =
+        aoc_common::find_day(&days, day).ok_or(format!("{year} day {day} is not implemented"))?;
+    let path = file_path(data_dir, &day);
+    let input = fs::read_to_string(&path)
+        .map_err(|err| format!("could not read {}: {err}", path.display()))?;
+
+    let answers = puzzle
+        .solve(&input, &[part], &Params::new())
+        .map_err(|err| {
+            print_error(&path, &input, &err);
+            err.to_string()
+        })?;
+    match answers.get(part).map(|a| &a.answer) {
+        Some(Answer::Picture(picture)) => Err(format!(
+            "{year} day {day} {part} is a picture, pass what it shows with --answer:\n{picture}"
+        )),
+        Some(Answer::NotImplemented) | None => {
+            Err(format!("{year} day {day} {part} is not implemented"))
+        }
+        Some(answer) => Ok(answer.to_string()),
+    }
+}
+
+/// Prints the trends of the timings in the history and fails if any step regressed.
+fn report(path: &Path, threshold: f64, year: Option<u16>, day: Option<u8>) -> bool {
+    let entries = match history::load(path) {
+        Ok(entries) => entries,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return false;
+        }
+    };
+
+    let trends: Vec<_> = history::trends(&entries)
+        .into_iter()
+        .filter(|t| year.is_none_or(|y| y == t.year) && day.is_none_or(|d| d == t.day))
+        .collect();
+    print!("{}", history::report(&trends, threshold / 100.0));
+
+    let regressed = trends
+        .iter()
+        .filter(|t| t.regressed(threshold / 100.0))
+        .count();
+    if regressed > 0 {
+        eprintln!("error: {regressed} timings regressed by more than {threshold}%");
+    }
+    regressed == 0
+}
+
+/// Solves the selected day, then again on every change of its input and flags changed answers.
+fn watch_day(args: &Args) -> ExitCode {
+    let puzzle = match select_puzzles(args) {
+        Ok(puzzles) => puzzles[0],
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::FAILURE;
+        }
+    };
+    let path = input_path(args, puzzle);
+    if path.as_os_str() == "-" {
+        eprintln!("error: the input cannot be watched when it is read from stdin");
+        return ExitCode::FAILURE;
+    }
+    let mut watcher = match watch::FileWatcher::new(&path) {
+        Ok(watcher) => watcher,
+        Err(err) => {
+            eprintln!("error: could not watch {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let mut previous = None;
+    loop {
+        if let Some(Run {
+            result: Ok(answers),
+            ..
+        }) = run(args, puzzle)
+        {
+            output::write_day(puzzle, &answers, previous.as_ref());
+            previous = Some(answers);
+        }
+
+        println!("Waiting for changes of {}", path.display());
+        if let Err(err) = watcher.wait() {
+            eprintln!("error: could not watch {}: {err}", path.display());
+            return ExitCode::FAILURE;
+        }
+    }
+}
+
+/// Runs the days selected by the arguments and reports their answers.
+fn run_days(args: &Args) -> ExitCode {
+    let puzzles = match select_puzzles(args) {
+        Ok(puzzles) => puzzles,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let runs = run_all(args, puzzles);
+    let mut success = runs.iter().all(|r| r.result.is_ok());
+
+    // only runs on the real inputs are comparable with each other and counting allocations
+    // slows the days down
+    if !args.no_history && args.params.is_empty() && args.input.is_none() && !args.profile_mem {
+        let mode = if runs.len() > 1 {
+            history::Mode::Parallel
+        } else {
+            history::Mode::Single
+        };
+        if let Err(message) = history::append(&args.history, &runs, mode) {
+            eprintln!("error: {message}");
+            success = false;
+        }
+    }
+
+    if args.record {
+        success &= record(args, &runs);
+        return exit_code(success);
+    }
+
+    let checks = if args.check {
+        match check(args, &runs) {
+            Ok(checks) => Some(checks),
+            Err(message) => {
+                eprintln!("error: {message}");
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        None
+    };
+    if let Some(checks) = &checks {
+        success &= checks.iter().flatten().flatten().all(|c| c.passed());
+    }
+
+    let parts = parts(args);
+    let written = output::write(args.format, &runs, &parts, checks.as_deref(), args.all);
+    if let Err(err) = written {
+        eprintln!("error: could not write the output: {err}");
+        success = false;
+    }
+    if args.profile_mem {
+        print!("\n{}", output::memory_summary(&runs));
+    }
+
+    exit_code(success)
+}
+
+/// Scaffolds a day in the workspace at `root` and lists the written files.
+fn new_day(
+    root: &Path,
+    year: u16,
+    day: u8,
+    title: Option<&str>,
+    data_dir: Option<PathBuf>,
+) -> bool {
+    let data_dir = data_dir.unwrap_or_else(|| root.join(default_data_dir(year)));
+    match scaffold::new_day(root, year, day, title, &data_dir) {
+        Ok(created) => {
+            for path in created {
+                println!("Wrote {}", path.display());
+            }
+            true
+        }
+        Err(message) => {
+            eprintln!("error: {message}");
+            false
+        }
+    }
+}
+
+fn exit_code(success: bool) -> ExitCode {
+    if success {
+        ExitCode::SUCCESS
+    } else {
+        ExitCode::FAILURE
+    }
+}
+
+fn select_puzzles(args: &Args) -> Result<Vec<&'static dyn Puzzle>, String> {
+    let years = match args.year {
+        Some(year) => {
+            let days =
+                years::find_year(year).ok_or(format!("there are no solutions for {year}"))?;
+            vec![(year, days)]
+        }
+        None => years::years(),
+    };
+
+    if args.all {
+        return Ok(years.into_iter().flat_map(|(_, days)| days).collect());
+    }
+
+    let (Some(year), Some(day)) = (args.year, args.day) else {
+        unreachable!("clap requires year and day unless all days are run");
+    };
+    let puzzle = aoc_common::find_day(&years[0].1, day)
+        .ok_or(format!("{year} day {day} is not implemented"))?;
+
+    Ok(vec![puzzle])
+}
+
+/// Runs the puzzles in parallel, unless memory is profiled, in the order they are given.
+fn run_all(args: &Args, puzzles: Vec<&'static dyn Puzzle>) -> Vec<Run> {
+    if args.profile_mem {
+        // the allocation counters are global, so the days must not run at the same time
+        memory::enable();
+        puzzles
+            .into_iter()
+            .filter_map(|puzzle| run(args, puzzle))
+            .collect()
+    } else {
+        // rayon keeps the order of the puzzles, so the days are still reported in order
+        puzzles
+            .into_par_iter()
+            .filter_map(|puzzle| run(args, puzzle))
+            .collect()
+    }
+}
+
+/// Solves the puzzle for its input, days without an input are skipped when running all days.
+///
+/// A panicking solution is reported as a failed run, so it does not abort the other days.
+fn run(args: &Args, puzzle: &'static dyn Puzzle) -> Option<Run> {
+    let (path, input) = read_input(args, puzzle);
+    let input = match input {
+        Ok(input) => input,
+        Err(err) if args.all && err.kind() == io::ErrorKind::NotFound => return None,
+        Err(err) => {
+            let message = format!("could not read {}: {err}", path.display());
+            eprintln!("error: {message}");
+            return Some(Run {
+                puzzle,
+                result: Err(message),
+                memory: None,
+            });
+        }
+    };
+
+    let parts = parts(args);
+    let params: Params = args.params.iter().cloned().collect();
+    let solve = || {
+        if args.profile_mem {
+            memory::solve(puzzle, &input, &parts, &params)
+                .map(|(answers, usage)| (answers, Some(usage)))
+        } else {
+            puzzle
+                .solve(&input, &parts, &params)
+                .map(|answers| (answers, None))
+        }
+    };
+    let (result, memory) = match panic::catch_unwind(AssertUnwindSafe(solve)) {
+        Ok(Ok((answers, memory))) => (Ok(answers), memory),
+        Ok(Err(err)) => {
+            print_error(&path, &input, &err);
+            (Err(err.to_string()), None)
+        }
+        Err(payload) => {
+            let message = format!(
+                "{} day {} panicked: {}",
+                puzzle.year(),
+                puzzle.day(),
+                panic_message(payload.as_ref())
+            );
+            eprintln!("error: {message}");
+            (Err(message), None)
+        }
+    };
+
+    Some(Run {
+        puzzle,
+        result,
+        memory,
+    })
+}
+
+/// Reads the input from `--input` if given and otherwise from the data directory.
+fn read_input(args: &Args, puzzle: &dyn Puzzle) -> (PathBuf, io::Result<String>) {
+    let path = input_path(args, puzzle);
+    if path.as_os_str() == "-" {
+        return (PathBuf::from("<stdin>"), io::read_to_string(io::stdin()));
+    }
+    let input = fs::read_to_string(&path);
+
+    (path, input)
+}
+
+/// Path of the input file, `-` stands for stdin.
+fn input_path(args: &Args, puzzle: &dyn Puzzle) -> PathBuf {
+    args.input
+        .clone()
+        .unwrap_or_else(|| file_path(&data_dir(args, puzzle.year()), &puzzle.day()))
+}
+
+/// Compares the answers of all runs with the accepted ones and reports every difference.
+///
+/// The result has the checks of both parts per run, which are `None` for parts that were not
+/// solved.
+fn check(args: &Args, runs: &[Run]) -> Result<Vec<[Option<Check>; 2]>, String> {
+    let known = load_answers(args, runs)?;
+
+    Ok(runs
+        .iter()
+        .map(|run| {
+            let (year, day) = (run.puzzle.year(), run.puzzle.day());
+            let Ok(answers) = &run.result else {
+                return [None; 2];
+            };
+
+            Part::ALL.map(|part| {
+                let answer = &answers.get(part)?.answer;
+                let check = known[&year].check(day, part, answer);
+                match check {
+                    Check::Ok | Check::NotImplemented => {}
+                    Check::Missing => {
+                        eprintln!("error: {year} day {day} {part} has no accepted answer")
+                    }
+                    Check::Mismatch => eprintln!(
+                        "error: {year} day {day} {part} is {} but {} was accepted",
+                        answer.to_line(),
+                        known[&year].get(day, part).unwrap_or_default()
+                    ),
+                }
+                Some(check)
+            })
+        })
+        .collect())
+}
+
+/// Stores the answers of all successful runs as the accepted ones.
+fn record(args: &Args, runs: &[Run]) -> bool {
+    let mut known = match load_answers(args, runs) {
+        Ok(known) => known,
+        Err(message) => {
+            eprintln!("error: {message}");
+            return false;
+        }
+    };
+
+    for run in runs {
+        let Ok(answers) = &run.result else {
+            continue;
+        };
+        let known = known.get_mut(&run.puzzle.year()).unwrap();
+        for part in Part::ALL {
+            match answers.get(part).map(|a| &a.answer) {
+                Some(Answer::NotImplemented) => known.remove(run.puzzle.day(), part),
+                Some(answer) => known.insert(run.puzzle.day(), part, answer.to_line().into_owned()),
+                None => {}
+            }
+        }
+    }
+
+    let mut success = true;
+    for (year, known) in &known {
+        let path = answers_path(args, *year);
+        match known.save(&path) {
+            Ok(()) => println!("Recorded answers of {year} in {}", path.display()),
+            Err(message) => {
+                eprintln!("error: {message}");
+                success = false;
+            }
+        }
+    }
+
+    success
+}
+
+/// Loads the accepted answers of every year that has runs.
+fn load_answers(args: &Args, runs: &[Run]) -> Result<BTreeMap<u16, KnownAnswers>, String> {
+    let mut known = BTreeMap::new();
+    for run in runs {
+        let year = run.puzzle.year();
+        if let Entry::Vacant(entry) = known.entry(year) {
+            entry.insert(KnownAnswers::load(&answers_path(args, year))?);
+        }
+    }
+
+    Ok(known)
+}
+
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+/// Prints the error and points at the offending spot of the input, similar to rustc diagnostics.
+///
+/// Stderr is locked for the whole diagnostic, so the ones of days solved in parallel do not mix.
+fn print_error(path: &Path, input: &str, err: &Error) {
+    let mut stderr = io::stderr().lock();
+    // there is nowhere left to report a failed write to stderr
+    let _ = write_diagnostic(&mut stderr, path, input, err);
+}
+
+fn write_diagnostic(w: &mut impl Write, path: &Path, input: &str, err: &Error) -> io::Result<()> {
+    writeln!(w, "error: {err}")?;
+
+    let Some(location) = err.source.location else {
+        return Ok(());
+    };
+    writeln!(
+        w,
+        "  --> {}:{}:{}",
+        path.display(),
+        location.line,
+        location.column
+    )?;
+    if let Some(line) = input.lines().nth(location.line - 1) {
+        let gutter = " ".repeat(location.line.to_string().len());
+        writeln!(w, "{gutter} |")?;
+        writeln!(w, "{} | {line}", location.line)?;
+        writeln!(w, "{gutter} | {}^", " ".repeat(location.column - 1))?;
+    }
+
+    Ok(())
+}
+
+fn parts(args: &Args) -> Vec<Part> {
+    match args.part.and_then(Part::from_number) {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+fn data_dir(args: &Args, year: u16) -> PathBuf {
+    args.data_dir
+        .clone()
+        .unwrap_or_else(|| default_data_dir(year))
+}
+
+fn default_data_dir(year: u16) -> PathBuf {
+    Path::new(&year.to_string()).join("data")
+}
+
+fn answers_path(args: &Args, year: u16) -> PathBuf {
+    args.answers
+        .clone()
+        .unwrap_or_else(|| default_answers_path(year))
+}
+
+fn default_answers_path(year: u16) -> PathBuf {
+    Path::new(&year.to_string()).join("answers.txt")
+}
+
+fn file_path(data_dir: &Path, day: &u8) -> PathBuf {
+    data_dir.join(format!("day{}.txt", day))
+}
+
+/// Path in the temporary directory that is unique to the test process.
+#[cfg(test)]
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("aoc-{}-{name}", std::process::id()))
+}
+
+#[test]
+fn test_new_day_without_title() {
+    let root = temp_path("new-day");
+    let days_dir = root.join("2024/src/days");
+    fs::create_dir_all(&days_dir).unwrap();
+    fs::write(days_dir.join("mod.rs"), "mod day01;\n").unwrap();
+
+    let cli = Cli::try_parse_from(["aoc", "new", "--year", "2024", "--day", "7"]).unwrap();
+    let Some(Command::New {
+        year,
+        day,
+        title,
+        data_dir,
+    }) = cli.command
+    else {
+        panic!("expected the new command");
+    };
+    let created = new_day(&root, year, day, title.as_deref(), data_dir);
+    let source = fs::read_to_string(days_dir.join("day07.rs"));
+    let days_mod = fs::read_to_string(days_dir.join("mod.rs"));
+    let input = root.join("2024/data/day7.txt").exists();
+    fs::remove_dir_all(&root).unwrap();
+
+    assert!(created);
+    assert!(source
+        .unwrap()
+        .contains(r#"const TITLE: &'static str = "Day 7";"#));
+    assert_eq!(days_mod.unwrap(), "mod day01;\nmod day07;\n");
+    assert!(input);
+}
+
+/// Puzzle of 2023 that answers its day in part one, except for day 2 which panics.
+#[cfg(test)]
+struct Fake<const DAY: u8>;
+
+#[cfg(test)]
+impl<const DAY: u8> aoc_common::Solution for Fake<DAY> {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = DAY;
+    const TITLE: &'static str = "Fake";
+
+    type Input = ();
+
+    fn parse(&self, _input: &str) -> Result<(), aoc_common::SolveError> {
+        Ok(())
+    }
+
+    fn part_one(&self, _input: &()) -> Result<Answer, aoc_common::SolveError> {
+        if DAY == 2 {
+            panic!("day {DAY} is broken");
+        }
+        Ok(Answer::from(DAY))
+    }
+
+    fn part_two(&self, _input: &()) -> Result<Answer, aoc_common::SolveError> {
+        Ok(Answer::NotImplemented)
+    }
+}
+
+#[test]
+fn test_panicking_day_does_not_abort_the_others() {
+    let data_dir = temp_path("panicking-day");
+    fs::create_dir_all(&data_dir).unwrap();
+    for day in 1..=4 {
+        fs::write(data_dir.join(format!("day{day}.txt")), "").unwrap();
+    }
+    let data_dir_arg = data_dir.to_str().unwrap();
+    let cli = Cli::try_parse_from(["aoc", "--year", "2023", "--all", "--data-dir", data_dir_arg])
+        .unwrap();
+    let puzzles: Vec<&'static dyn Puzzle> = vec![&Fake::<1>, &Fake::<2>, &Fake::<3>, &Fake::<4>];
+
+    let runs = run_all(&cli.args, puzzles);
+    fs::remove_dir_all(&data_dir).unwrap();
+
+    let days: Vec<u8> = runs.iter().map(|run| run.puzzle.day()).collect();
+    assert_eq!(days, [1, 2, 3, 4]);
+    assert_eq!(
+        runs[1].result,
+        Err(String::from("2023 day 2 panicked: day 2 is broken"))
+    );
+    for run in [&runs[0], &runs[2], &runs[3]] {
+        let answers = run.result.as_ref().unwrap();
+        let answer = &answers.get(Part::One).unwrap().answer;
+        assert_eq!(*answer, Answer::from(run.puzzle.day()));
+    }
+}
+
+#[test]
+fn test_fetch_needs_session_only_for_requests() {
+    let data_dir = temp_path("fetch-cached");
+    fs::create_dir_all(&data_dir).unwrap();
+    fs::write(data_dir.join("day7.txt"), "1\n2\n").unwrap();
+    let server = Server {
+        session: None,
+        base_url: String::from("http://127.0.0.1:9"),
+    };
+
+    let cached = fetch(2023, 7, &data_dir, false, &server);
+    let forced = fetch(2023, 7, &data_dir, true, &server);
+    fs::remove_dir_all(&data_dir).unwrap();
+
+    assert!(cached);
+    assert!(!forced);
+    assert_eq!(
+        server.client().err().unwrap(),
+        "a session is required, pass --session or set AOC_SESSION"
+    );
+}
@@ -0,0 +1,135 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicBool, AtomicI64, AtomicU64, Ordering},
+};
+
+use aoc_common::{Answers, Error, Params, Part, Puzzle};
+
+/// Allocator that counts the allocations of the system allocator once it is enabled.
+///
+/// The counters are shared by all threads, so measurements are only meaningful while a single
+/// puzzle is solved at a time.
+pub struct CountingAllocator;
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+static ALLOCATED: AtomicU64 = AtomicU64::new(0);
+/// Bytes in use, which gets negative when memory allocated before enabling is freed.
+static IN_USE: AtomicI64 = AtomicI64::new(0);
+static PEAK: AtomicI64 = AtomicI64::new(0);
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size as u64, Ordering::Relaxed);
+    record_in_use(size as i64);
+}
+
+fn record_in_use(change: i64) {
+    let in_use = IN_USE.fetch_add(change, Ordering::Relaxed) + change;
+    PEAK.fetch_max(in_use, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        if ENABLED.load(Ordering::Relaxed) {
+            record_in_use(-(layout.size() as i64));
+        }
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() && ENABLED.load(Ordering::Relaxed) {
+            ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+            ALLOCATED.fetch_add(new_size as u64, Ordering::Relaxed);
+            record_in_use(new_size as i64 - layout.size() as i64);
+        }
+        new_ptr
+    }
+}
+
+/// Starts counting allocations.
+pub fn enable() {
+    ENABLED.store(true, Ordering::Relaxed);
+}
+
+/// Heap usage of a step of solving a puzzle.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    /// Number of allocations, growing an allocation counts as one as well.
+    pub allocations: u64,
+    /// Total bytes allocated, including memory that has been freed again.
+    pub allocated: u64,
+    /// Most bytes in use at once on top of those in use when the step started.
+    pub peak: u64,
+}
+
+/// Heap usage of parsing and of the solved parts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    pub parse: Usage,
+    pub part_one: Option<Usage>,
+    pub part_two: Option<Usage>,
+}
+
+impl MemoryUsage {
+    pub fn get(&self, part: Part) -> Option<Usage> {
+        match part {
+            Part::One => self.part_one,
+            Part::Two => self.part_two,
+        }
+    }
+}
+
+/// Runs `f` and measures its heap usage.
+fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+    let in_use = IN_USE.load(Ordering::Relaxed);
+    PEAK.store(in_use, Ordering::Relaxed);
+
+    let result = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak: (PEAK.load(Ordering::Relaxed) - in_use).max(0) as u64,
+    };
+    (result, usage)
+}
+
+/// Solves the puzzle like [`Puzzle::solve`] and measures the heap usage of every step.
+pub fn solve(
+    puzzle: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    params: &Params,
+) -> Result<(Answers, MemoryUsage), Error> {
+    let mut usage = MemoryUsage::default();
+    let answers = puzzle.solve_with(input, parts, params, &mut |part, step| {
+        let ((), step_usage) = measure(step);
+        match part {
+            None => usage.parse = step_usage,
+            Some(Part::One) => usage.part_one = Some(step_usage),
+            Some(Part::Two) => usage.part_two = Some(step_usage),
+        }
+    })?;
+
+    Ok((answers, usage))
+}
@@ -0,0 +1,351 @@
+use std::{io, time::Duration};
+
+use aoc_common::{Answer, Answers, Part, Puzzle};
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{
+    answers::Check,
+    table::{format_bytes, format_duration, Table},
+    Run,
+};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// Human readable answers, a table when running several days.
+    #[default]
+    Text,
+    /// Array with a record per part.
+    Json,
+    /// Row with a record per part.
+    Csv,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Error,
+}
+
+/// Outcome of a single part of a day, as written in the structured formats.
+#[derive(Debug, Serialize)]
+pub struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    /// Rows of a picture are separated by newlines, a part that is not implemented has none.
+    answer: Option<String>,
+    status: Status,
+    parse_time_ns: Option<u64>,
+    time_ns: Option<u64>,
+    error: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    check: Option<Check>,
+}
+
+/// Records of the solved `parts` of every run, `checks` holds the result of `--check` per run.
+fn records<'a>(
+    runs: &'a [Run],
+    parts: &[Part],
+    checks: Option<&[[Option<Check>; 2]]>,
+) -> Vec<Record<'a>> {
+    let nanos = |d: Duration| d.as_nanos().try_into().ok();
+
+    runs.iter()
+        .enumerate()
+        .flat_map(|(i, run)| {
+            parts.iter().map(move |&part| {
+                let (answer, status, parse_time_ns, time_ns, error) = match &run.result {
+                    Ok(answers) => {
+                        let answer = answers.get(part).expect("all parts have been solved");
+                        let (text, status) = match &answer.answer {
+                            Answer::NotImplemented => (None, Status::Unsolved),
+                            solved => (Some(solved.to_string()), Status::Solved),
+                        };
+                        (
+                            text,
+                            status,
+                            nanos(answers.parse_elapsed),
+                            nanos(answer.elapsed),
+                            None,
+                        )
+                    }
+                    Err(message) => (None, Status::Error, None, None, Some(message.as_str())),
+                };
+
+                Record {
+                    year: run.puzzle.year(),
+                    day: run.puzzle.day(),
+                    part: part.number(),
+                    answer,
+                    status,
+                    parse_time_ns,
+                    time_ns,
+                    error,
+                    check: checks.and_then(|c| c[i][part as usize]),
+                }
+            })
+        })
+        .collect()
+}
+
+/// Writes the runs to stdout, a text summary is a table if `all` days were run or checked.
+pub fn write(
+    format: Format,
+    runs: &[Run],
+    parts: &[Part],
+    checks: Option<&[[Option<Check>; 2]]>,
+    all: bool,
+) -> io::Result<()> {
+    match format {
+        Format::Json => write_json(io::stdout().lock(), &records(runs, parts, checks)),
+        Format::Csv => write_csv(io::stdout().lock(), &records(runs, parts, checks)),
+        Format::Text if all || checks.is_some() => {
+            print!("{}", summary(runs, checks));
+            Ok(())
+        }
+        Format::Text => {
+            if let [Run {
+                puzzle,
+                result: Ok(answers),
+                ..
+            }] = runs
+            {
+                write_day(*puzzle, answers, None);
+            }
+            Ok(())
+        }
+    }
+}
+
+/// Writes the answers of a single day as text, answers that differ from the `previous` ones are
+/// flagged.
+pub fn write_day(puzzle: &dyn Puzzle, answers: &Answers, previous: Option<&Answers>) {
+    println!("Day {}:", puzzle.day());
+    println!("\tParse: {}", format_duration(answers.parse_elapsed));
+    for (part, label) in [(Part::One, "Part one"), (Part::Two, "Part two")] {
+        let Some(answer) = answers.get(part) else {
+            continue;
+        };
+        let elapsed = format_duration(answer.elapsed);
+        let changed = match previous.and_then(|p| p.get(part)) {
+            Some(before) if before.answer != answer.answer => match before.answer {
+                Answer::Picture(_) => ", changed".to_string(),
+                _ => format!(", changed from {}", before.answer),
+            },
+            _ => String::new(),
+        };
+        match &answer.answer {
+            Answer::Picture(picture) => {
+                println!("\t{label}: ({elapsed}){changed}");
+                for row in picture.lines() {
+                    println!("\t\t{row}");
+                }
+            }
+            answer => println!("\t{label}: {answer} ({elapsed}){changed}"),
+        }
+    }
+}
+
+fn write_json(mut w: impl io::Write, records: &[Record]) -> io::Result<()> {
+    serde_json::to_writer_pretty(&mut w, records)?;
+    writeln!(w)
+}
+
+fn write_csv(w: impl io::Write, records: &[Record]) -> io::Result<()> {
+    let mut writer = csv::Writer::from_writer(w);
+    for record in records {
+        writer.serialize(record)?;
+    }
+    writer.flush()
+}
+
+/// Table with both answers and timings of every run, with a check column if `checks` are given.
+fn summary(runs: &[Run], checks: Option<&[[Option<Check>; 2]]>) -> Table {
+    let mut header = vec![
+        "Year", "Day", "Title", "Parse", "Part one", "Time", "Part two", "Time",
+    ];
+    if checks.is_some() {
+        header.push("Check");
+    }
+    let mut table = Table::new(&header);
+
+    for (i, run) in runs.iter().enumerate() {
+        let mut row = vec![
+            run.puzzle.year().to_string(),
+            run.puzzle.day().to_string(),
+            run.puzzle.title().to_string(),
+        ];
+        match &run.result {
+            Ok(answers) => {
+                row.push(format_duration(answers.parse_elapsed));
+                for part in Part::ALL {
+                    match answers.get(part) {
+                        Some(answer) => {
+                            row.push(cell(&answer.answer));
+                            row.push(format_duration(answer.elapsed));
+                        }
+                        None => row.extend([String::new(), String::new()]),
+                    }
+                }
+            }
+            Err(_) => row.extend(["", "error", "", "error", ""].map(String::from)),
+        }
+        if let Some(checks) = checks {
+            let check = match run.result {
+                Ok(_) => checks[i].iter().flatten().max().map_or("", |c| c.as_str()),
+                Err(_) => "error",
+            };
+            row.push(check.to_string());
+        }
+        table.push(row);
+    }
+
+    table
+}
+
+/// Answer as a table cell, which cannot hold the rows of a picture.
+fn cell(answer: &Answer) -> String {
+    match answer {
+        Answer::Picture(picture) => format!("picture of {} rows", picture.lines().count()),
+        answer => answer.to_string(),
+    }
+}
+
+/// Table with the heap usage of every step of the profiled runs.
+pub fn memory_summary(runs: &[Run]) -> Table {
+    let mut table = Table::new(&[
+        "Year",
+        "Day",
+        "Step",
+        "Allocations",
+        "Allocated",
+        "Peak heap",
+    ]);
+
+    for run in runs {
+        let Some(memory) = &run.memory else {
+            continue;
+        };
+        let steps = [
+            ("parse", Some(memory.parse)),
+            ("part one", memory.get(Part::One)),
+            ("part two", memory.get(Part::Two)),
+        ];
+        for (step, usage) in steps {
+            let Some(usage) = usage else {
+                continue;
+            };
+            table.push(vec![
+                run.puzzle.year().to_string(),
+                run.puzzle.day().to_string(),
+                step.to_string(),
+                usage.allocations.to_string(),
+                format_bytes(usage.allocated),
+                format_bytes(usage.peak),
+            ]);
+        }
+    }
+
+    table
+}
+
+/// Run of 2022 day 10, whose part two is a picture.
+#[cfg(test)]
+fn run(result: Result<Answers, String>) -> Run {
+    let days = aoc::years::find_year(2022).unwrap();
+
+    Run {
+        puzzle: aoc_common::find_day(&days, 10).unwrap(),
+        result,
+        memory: None,
+    }
+}
+
+#[cfg(test)]
+fn answers(part_one: Answer, part_two: Answer) -> Answers {
+    let part = |answer| {
+        Some(aoc_common::PartAnswer {
+            answer,
+            elapsed: Duration::from_nanos(20),
+        })
+    };
+
+    Answers {
+        parse_elapsed: Duration::from_nanos(10),
+        part_one: part(part_one),
+        part_two: part(part_two),
+    }
+}
+
+#[test]
+fn test_json_records() {
+    let runs = [
+        run(Ok(answers(Answer::Integer(13140), Answer::NotImplemented))),
+        run(Err(String::from("could not read day10.txt"))),
+    ];
+    let mut json = Vec::new();
+
+    write_json(&mut json, &records(&runs, &Part::ALL, None)).unwrap();
+
+    let records: Vec<serde_json::Value> = serde_json::from_slice(&json).unwrap();
+    let fields: Vec<&str> = records[0]
+        .as_object()
+        .unwrap()
+        .keys()
+        .map(String::as_str)
+        .collect();
+    assert_eq!(
+        fields,
+        [
+            "answer",
+            "day",
+            "error",
+            "parse_time_ns",
+            "part",
+            "status",
+            "time_ns",
+            "year"
+        ]
+    );
+    assert_eq!(
+        records[0],
+        serde_json::json!({
+            "year": 2022,
+            "day": 10,
+            "part": 1,
+            "answer": "13140",
+            "status": "solved",
+            "parse_time_ns": 10,
+            "time_ns": 20,
+            "error": null,
+        })
+    );
+    let statuses: Vec<&str> = records
+        .iter()
+        .map(|r| r["status"].as_str().unwrap())
+        .collect();
+    assert_eq!(statuses, ["solved", "unsolved", "error", "error"]);
+    assert_eq!(records[1]["answer"], serde_json::Value::Null);
+    assert_eq!(records[2]["error"], "could not read day10.txt");
+    assert_eq!(records[2]["time_ns"], serde_json::Value::Null);
+}
+
+#[test]
+fn test_csv_escapes_pictures() {
+    let picture = Answer::picture(["#.\"", ".#,"]);
+    let runs = [run(Ok(answers(Answer::Integer(1), picture)))];
+    let mut csv = Vec::new();
+
+    write_csv(&mut csv, &records(&runs, &[Part::Two], None)).unwrap();
+
+    assert_eq!(
+        String::from_utf8(csv).unwrap(),
+        concat!(
+            "year,day,part,answer,status,parse_time_ns,time_ns,error\n",
+            "2022,10,2,\"#.\"\"\n.#,\",solved,10,20,\n",
+        )
+    );
+}
@@ -0,0 +1,212 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+/// Where the sources of a year live and how its days are named.
+struct Layout {
+    year: u16,
+    /// Directory of the crate, relative to the workspace root.
+    crate_dir: &'static str,
+    /// Whether day modules are zero-padded to two digits, e.g. `day07` instead of `day7`.
+    padded: bool,
+    /// Names of the free functions the parts delegate to.
+    part_fns: [&'static str; 2],
+}
+
+static LAYOUTS: &[Layout] = &[
+    Layout {
+        year: 2022,
+        crate_dir: "2022/rust",
+        padded: false,
+        part_fns: ["solve_part_one", "solve_part_two"],
+    },
+    Layout {
+        year: 2023,
+        crate_dir: "2023",
+        padded: false,
+        part_fns: ["solve_first", "solve_second"],
+    },
+    Layout {
+        year: 2024,
+        crate_dir: "2024",
+        padded: true,
+        part_fns: ["solve_first", "solve_second"],
+    },
+];
+
+const TEMPLATE: &str = include_str!("../templates/day.rs");
+
+/// Writes the solution template of a new day into the workspace at `root`, registers it and
+/// creates an empty input file. Without a title the day is named `Day {day}`.
+///
+/// Returns the written files. Nothing is written if the day already exists and nothing is left
+/// behind if writing fails.
+pub fn new_day(
+    root: &Path,
+    year: u16,
+    day: u8,
+    title: Option<&str>,
+    data_dir: &Path,
+) -> Result<Vec<PathBuf>, String> {
+    let layout = LAYOUTS
+        .iter()
+        .find(|l| l.year == year)
+        .ok_or(format!("there is no crate for {year}"))?;
+    if !(1..=25).contains(&day) {
+        return Err(format!("{day} is not a day of the advent calendar"));
+    }
+    let placeholder = format!("Day {day}");
+    let title = title.unwrap_or(&placeholder);
+    if title.trim().is_empty() || title.contains(char::is_control) {
+        return Err(format!("{title:?} is not a puzzle title"));
+    }
+
+    let module = if layout.padded {
+        format!("day{day:02}")
+    } else {
+        format!("day{day}")
+    };
+    let days_dir = root.join(layout.crate_dir).join("src").join("days");
+    let source_path = days_dir.join(format!("{module}.rs"));
+    let mod_path = days_dir.join("mod.rs");
+    let input_path = data_dir.join(format!("day{day}.txt"));
+
+    if source_path.exists() {
+        return Err(format!("{} already exists", source_path.display()));
+    }
+    let days_mod = fs::read_to_string(&mod_path)
+        .map_err(|err| format!("could not read {}: {err}", mod_path.display()))?;
+    let days_mod = register(&days_mod, &module)?;
+    let source = render(layout, day, title);
+    fs::create_dir_all(data_dir)
+        .map_err(|err| format!("could not create {}: {err}", data_dir.display()))?;
+
+    let mut files = vec![(source_path, source)];
+    if !input_path.exists() {
+        files.push((input_path, String::new()));
+    }
+    // the day is registered last and the new files are removed again if a write fails, so
+    // a failed run does not leave a half-registered day behind
+    let mut written: Vec<PathBuf> = Vec::new();
+    for (path, content) in files.iter().chain([&(mod_path, days_mod)]) {
+        if let Err(err) = fs::write(path, content) {
+            for path in &written {
+                let _ = fs::remove_file(path);
+            }
+            return Err(format!("could not write {}: {err}", path.display()));
+        }
+        written.push(path.clone());
+    }
+
+    Ok(written)
+}
+
+/// Source of the solution template, the title is escaped as a string literal.
+fn render(layout: &Layout, day: u8, title: &str) -> String {
+    // the title goes last, so placeholders in it are not replaced
+    TEMPLATE
+        .replace("{{struct}}", &format!("Day{day:02}"))
+        .replace("{{day}}", &day.to_string())
+        .replace("{{part_one}}", layout.part_fns[0])
+        .replace("{{part_two}}", layout.part_fns[1])
+        .replace("{{title}}", &format!("{title:?}"))
+}
+
+/// Adds the `mod` declaration of a day to the `days/mod.rs` source, keeping the declarations in
+/// the order rustfmt uses. The day registers itself, so nothing else has to be listed.
+fn register(days_mod: &str, module: &str) -> Result<String, String> {
+    let mut lines: Vec<String> = days_mod.lines().map(String::from).collect();
+    let module_name = |line: &str| {
+        line.trim_start_matches("pub ")
+            .strip_prefix("mod ")
+            .and_then(|l| l.strip_suffix(';'))
+            .map(String::from)
+    };
+
+    let modules: Vec<(usize, String)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| module_name(l).map(|m| (i, m)))
+        .collect();
+    if modules.iter().any(|(_, m)| m == module) {
+        return Err(format!("{module} is already declared"));
+    }
+    let (last_module, _) = modules.last().ok_or("days/mod.rs has no day modules")?;
+    let position = modules
+        .iter()
+        .find(|(_, m)| m.as_str() > module)
+        .map_or(last_module + 1, |(i, _)| *i);
+    lines.insert(position, format!("mod {module};"));
+
+    Ok(lines.join("\n") + "\n")
+}
+
+#[test]
+fn test_register_keeps_order() {
+    let days_mod = concat!(
+        "use aoc_common::Puzzle;\n",
+        "\n",
+        "mod day1;\n",
+        "mod day10;\n",
+        "mod day3;\n",
+        "\n",
+        "pub const YEAR: u16 = 2022;\n",
+    );
+
+    let actual = register(days_mod, "day2").unwrap();
+
+    assert_eq!(
+        actual,
+        concat!(
+            "use aoc_common::Puzzle;\n",
+            "\n",
+            "mod day1;\n",
+            "mod day10;\n",
+            "mod day2;\n",
+            "mod day3;\n",
+            "\n",
+            "pub const YEAR: u16 = 2022;\n",
+        )
+    );
+}
+
+#[test]
+fn test_register_last_day() {
+    let days_mod = concat!("mod day01;\n", "\n", "pub const YEAR: u16 = 2024;\n");
+
+    let actual = register(days_mod, "day07").unwrap();
+
+    assert_eq!(
+        actual,
+        concat!(
+            "mod day01;\n",
+            "mod day07;\n",
+            "\n",
+            "pub const YEAR: u16 = 2024;\n",
+        )
+    );
+}
+
+#[test]
+fn test_render_escapes_title() {
+    let source = render(&LAYOUTS[2], 7, r#"Bridge "Repair" \ {{day}}"#);
+
+    assert!(source.contains(r#"const TITLE: &'static str = "Bridge \"Repair\" \\ {{day}}";"#));
+    assert!(source.contains("pub struct Day07;"));
+}
+
+#[test]
+fn test_new_day_rejects_title() {
+    let data_dir = Path::new("does/not/exist");
+
+    assert_eq!(
+        new_day(Path::new(""), 2024, 7, Some(" "), data_dir),
+        Err(r#"" " is not a puzzle title"#.to_string())
+    );
+    assert_eq!(
+        new_day(Path::new(""), 2024, 7, Some("Bridge\nRepair"), data_dir),
+        Err(r#""Bridge\nRepair" is not a puzzle title"#.to_string())
+    );
+    assert!(!data_dir.exists());
+}
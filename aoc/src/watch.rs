@@ -0,0 +1,52 @@
+use std::{
+    ffi::OsString,
+    io,
+    path::{Path, PathBuf},
+};
+
+use inotify::{Inotify, WatchMask};
+
+/// Waits for changes of a file using inotify.
+///
+/// The directory of the file is watched instead of the file itself, because editors often save
+/// by replacing the file, which would silently end a watch on the old one.
+pub struct FileWatcher {
+    inotify: Inotify,
+    file_name: OsString,
+}
+
+impl FileWatcher {
+    pub fn new(path: &Path) -> io::Result<Self> {
+        let file_name = path.file_name().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("{} is not a file", path.display()),
+            )
+        })?;
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        };
+
+        let inotify = Inotify::init()?;
+        inotify
+            .watches()
+            .add(&dir, WatchMask::CLOSE_WRITE | WatchMask::MOVED_TO)?;
+
+        Ok(FileWatcher {
+            inotify,
+            file_name: file_name.to_owned(),
+        })
+    }
+
+    /// Blocks until the file has been written or replaced.
+    pub fn wait(&mut self) -> io::Result<()> {
+        let mut buffer = [0; 4096];
+        loop {
+            let mut events = self.inotify.read_events_blocking(&mut buffer)?;
+            if events.any(|event| event.name == Some(self.file_name.as_os_str())) {
+                return Ok(());
+            }
+        }
+    }
+}
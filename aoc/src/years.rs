@@ -0,0 +1,35 @@
+use aoc_common::Puzzle;
+
+/// Lists the puzzles of a year ordered by day.
+type Registry = fn() -> Vec<&'static dyn Puzzle>;
+
+/// Registries of all years that have solutions. A new year is plugged in by adding its crate
+/// as a dependency and listing its registry here.
+static YEARS: &[(u16, Registry)] = &[
+    (adventofcode2022::days::YEAR, adventofcode2022::days::days),
+    (aoc2023::days::YEAR, aoc2023::days::days),
+    (aoc2024::days::YEAR, aoc2024::days::days),
+];
+
+/// Puzzles of all years, ordered by day.
+pub fn years() -> Vec<(u16, Vec<&'static dyn Puzzle>)> {
+    YEARS.iter().map(|(year, days)| (*year, days())).collect()
+}
+
+/// Looks up the puzzles of `year`, ordered by day.
+pub fn find_year(year: u16) -> Option<Vec<&'static dyn Puzzle>> {
+    YEARS
+        .iter()
+        .find(|(y, _)| *y == year)
+        .map(|(_, days)| days())
+}
+
+#[test]
+fn test_days_are_registered_once() {
+    for (year, days) in years() {
+        assert!(!days.is_empty(), "{year} has no registered days");
+        for pair in days.windows(2) {
+            assert_ne!(pair[0].day(), pair[1].day(), "{year} has a day twice");
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use aoc_common::{Answer, Solution, SolveError};
+
+pub struct {{struct}};
+
+aoc_common::register!({{struct}});
+
+impl Solution for {{struct}} {
+    const YEAR: u16 = super::YEAR;
+    const DAY: u8 = {{day}};
+    const TITLE: &'static str = {{title}};
+
+    type Input = Vec<String>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError> {
+        Ok(input.lines().map(String::from).collect())
+    }
+
+    fn part_one(&self, lines: &Self::Input) -> Result<Answer, SolveError> {
+        Ok({{part_one}}(lines))
+    }
+
+    fn part_two(&self, lines: &Self::Input) -> Result<Answer, SolveError> {
+        Ok({{part_two}}(lines))
+    }
+}
+
+fn {{part_one}}(_lines: &[String]) -> Answer {
+    Answer::NotImplemented
+}
+
+fn {{part_two}}(_lines: &[String]) -> Answer {
+    Answer::NotImplemented
+}
+
+// todo: replace with the example and the answers of the puzzle description
+#[cfg(test)]
+const EXAMPLE: &str = "";
+
+aoc_common::examples! {
+    solution: {{struct}},
+    part_one: {
+        EXAMPLE => "not implemented",
+    },
+    part_two: {
+        EXAMPLE => "not implemented",
+    },
+}
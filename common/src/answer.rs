@@ -0,0 +1,88 @@
+use std::{borrow::Cow, fmt};
+
+/// Answer of a single part of a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// Wide enough to hold the values of all primitive integer types.
+    Integer(i128),
+    Text(String),
+    /// Several lines that have to be read by a human, e.g. letters drawn on a screen.
+    Picture(String),
+    /// The part has not been solved yet.
+    NotImplemented,
+}
+
+impl Answer {
+    /// Builds a picture from its rows.
+    pub fn picture<I>(rows: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let rows: Vec<_> = rows
+            .into_iter()
+            .map(|row| row.as_ref().to_string())
+            .collect();
+        Answer::Picture(rows.join("\n"))
+    }
+
+    pub fn is_implemented(&self) -> bool {
+        *self != Answer::NotImplemented
+    }
+
+    /// Answer on a single line, the rows of a picture are separated by a literal `\n`.
+    ///
+    /// This is the form the answer is stored and compared in.
+    pub fn to_line(&self) -> Cow<'_, str> {
+        match self {
+            Answer::Picture(picture) => Cow::Owned(picture.replace('\n', "\\n")),
+            Answer::Text(text) => Cow::Borrowed(text),
+            _ => Cow::Owned(self.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(value) => write!(f, "{value}"),
+            Answer::Text(text) | Answer::Picture(text) => f.write_str(text),
+            Answer::NotImplemented => f.write_str("not implemented"),
+        }
+    }
+}
+
+macro_rules! from_integer {
+    ($($int:ty),+) => {
+        $(
+            impl From<$int> for Answer {
+                fn from(value: $int) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )+
+    };
+}
+
+from_integer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+#[test]
+fn test_to_line() {
+    assert_eq!(Answer::from(42_u64).to_line(), "42");
+    assert_eq!(Answer::from(-3_i32).to_line(), "-3");
+    assert_eq!(Answer::from("CMZ").to_line(), "CMZ");
+    assert_eq!(Answer::picture(["#.", ".#"]).to_line(), "#.\\n.#");
+    assert_eq!(Answer::picture(["#.", ".#"]).to_string(), "#.\n.#");
+}
@@ -0,0 +1,128 @@
+use std::fmt;
+
+/// Part of a puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    /// Number of the part as used on the website, i.e. 1 or 2.
+    pub fn number(self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+
+    pub fn from_number(number: u8) -> Option<Part> {
+        match number {
+            1 => Some(Part::One),
+            2 => Some(Part::Two),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "part one"),
+            Part::Two => write!(f, "part two"),
+        }
+    }
+}
+
+/// Position in the puzzle input, both line and column start at 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Location {
+    pub line: usize,
+    pub column: usize,
+}
+
+/// Problem a solver ran into, e.g. a malformed input.
+///
+/// The solver does not know which puzzle it is part of, that is added by [`Error`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+    pub location: Option<Location>,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        SolveError {
+            message: message.into(),
+            location: None,
+        }
+    }
+
+    /// Error for the input at the 0-based `line_index` and `column_index`.
+    pub fn at(line_index: usize, column_index: usize, message: impl Into<String>) -> Self {
+        SolveError {
+            message: message.into(),
+            location: Some(Location {
+                line: line_index + 1,
+                column: column_index + 1,
+            }),
+        }
+    }
+
+    /// Error for `token`, which has to be a slice of `line` at the 0-based `line_index`.
+    pub fn at_token(
+        line_index: usize,
+        line: &str,
+        token: &str,
+        message: impl Into<String>,
+    ) -> Self {
+        let offset = (token.as_ptr() as usize)
+            .checked_sub(line.as_ptr() as usize)
+            .filter(|o| *o <= line.len())
+            .unwrap_or(0);
+
+        SolveError::at(line_index, line[..offset].chars().count(), message)
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.location {
+            Some(Location { line, column }) => {
+                write!(f, "{} (line {line}, column {column})", self.message)
+            }
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// Error of a puzzle, knowing which year, day and part failed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Error {
+    pub year: u16,
+    pub day: u8,
+    /// The part that failed or `None` if the input could not be parsed.
+    pub part: Option<Part>,
+    pub source: SolveError,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day {}", self.year, self.day)?;
+        match self.part {
+            Some(part) => write!(f, " {part}")?,
+            None => write!(f, " input")?,
+        }
+        write!(f, ": {}", self.source)
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
@@ -0,0 +1,46 @@
+/// Generates a test per part that solves the examples of the puzzle description and compares
+/// the results with the answers given there.
+///
+/// The solution is an expression, so puzzle parameters that differ for the examples can be
+/// set on it, e.g. `solution: Day15 { row: 10, max_coordinate: 20 }`.
+///
+/// ```ignore
+/// aoc_common::examples! {
+///     solution: Day06,
+///     part_one: {
+///         "mjqjpqmgbljsphdztnvjfqwrcgsmlb" => "7",
+///         "bvwbjplbgvbhsrlpgdmjqwftvncz" => "5",
+///     },
+///     part_two: {
+///         "mjqjpqmgbljsphdztnvjfqwrcgsmlb" => "19",
+///     },
+/// }
+/// ```
+#[macro_export]
+macro_rules! examples {
+    (
+        solution: $solution:expr,
+        $($part:ident: { $($input:expr => $expected:expr),+ $(,)? }),+ $(,)?
+    ) => {
+        #[cfg(test)]
+        mod examples {
+            #[allow(unused_imports)]
+            use super::*;
+            use $crate::Solution;
+
+            $(
+                #[test]
+                fn $part() {
+                    let solution = $solution;
+                    $(
+                        let input = solution
+                            .parse(&$crate::input::normalize($input))
+                            .expect("example should be parsed");
+                        let answer = solution.$part(&input).expect("example should be solved");
+                        assert_eq!(answer.to_string(), $expected);
+                    )+
+                }
+            )+
+        }
+    };
+}
@@ -0,0 +1,348 @@
+//! Points, directions and distances on integer coordinates.
+//!
+//! Like in the [grid](crate::grid), `y` grows downwards, so [`Direction::Up`] decreases it.
+
+use std::{
+    cmp::Ordering,
+    fmt,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// Integer type that can be used for the coordinates of a point.
+pub trait Coordinate:
+    Copy + Ord + fmt::Debug + Add<Output = Self> + Sub<Output = Self> + Mul<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// Difference between the two values, which never overflows for unsigned types.
+    fn abs_diff(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+macro_rules! coordinate {
+    ($($int:ty),+) => {
+        $(
+            impl Coordinate for $int {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                fn checked_add(self, other: Self) -> Option<Self> {
+                    <$int>::checked_add(self, other)
+                }
+
+                fn checked_sub(self, other: Self) -> Option<Self> {
+                    <$int>::checked_sub(self, other)
+                }
+            }
+        )+
+    };
+}
+
+coordinate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+/// Point in the plane, which also serves as the vector from the origin to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T: Coordinate> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+
+    pub fn origin() -> Self {
+        Point::new(T::ZERO, T::ZERO)
+    }
+
+    /// Neighbouring point in `direction`.
+    ///
+    /// Panics like the arithmetic of `T` if the point is at the edge of the coordinates.
+    pub fn step(self, direction: Direction) -> Self {
+        self.checked_step(direction)
+            .unwrap_or_else(|| panic!("{self:?} has no neighbour in {direction:?}"))
+    }
+
+    /// Neighbouring point in `direction` or `None` if it cannot be represented by `T`.
+    pub fn checked_step(self, direction: Direction) -> Option<Self> {
+        let Point { x, y } = self;
+        Some(match direction {
+            Direction::Up => Point::new(x, y.checked_sub(T::ONE)?),
+            Direction::Right => Point::new(x.checked_add(T::ONE)?, y),
+            Direction::Down => Point::new(x, y.checked_add(T::ONE)?),
+            Direction::Left => Point::new(x.checked_sub(T::ONE)?, y),
+        })
+    }
+
+    /// Sum of the distances along both axes, also known as taxicab distance.
+    pub fn manhattan_distance(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Largest of the distances along both axes, i.e. the number of king moves on a chess
+    /// board.
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Z component of the cross product of the vectors, positive if `other` is clockwise of
+    /// `self` as `y` grows downwards.
+    pub fn cross(self, other: Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+
+    pub fn dot(self, other: Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+/// Point in space, which also serves as the vector from the origin to it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Coordinate> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn origin() -> Self {
+        Point3::new(T::ZERO, T::ZERO, T::ZERO)
+    }
+
+    /// Sum of the distances along all axes.
+    pub fn manhattan_distance(self, other: Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    /// Largest of the distances along all axes.
+    pub fn chebyshev_distance(self, other: Self) -> T {
+        self.x
+            .abs_diff(other.x)
+            .max(self.y.abs_diff(other.y))
+            .max(self.z.abs_diff(other.z))
+    }
+
+    /// The 6 points that share a face with this one in a grid of cubes.
+    pub fn neighbours6(self) -> [Self; 6] {
+        let Point3 { x, y, z } = self;
+        let one = T::ONE;
+        [
+            Point3::new(x - one, y, z),
+            Point3::new(x + one, y, z),
+            Point3::new(x, y - one, z),
+            Point3::new(x, y + one, z),
+            Point3::new(x, y, z - one),
+            Point3::new(x, y, z + one),
+        ]
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+macro_rules! vector_ops {
+    ($point:ident { $($axis:ident),+ }) => {
+        impl<T: Coordinate> Add for $point<T> {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                $point { $($axis: self.$axis + other.$axis),+ }
+            }
+        }
+
+        impl<T: Coordinate> Sub for $point<T> {
+            type Output = Self;
+
+            fn sub(self, other: Self) -> Self {
+                $point { $($axis: self.$axis - other.$axis),+ }
+            }
+        }
+
+        impl<T: Coordinate> AddAssign for $point<T> {
+            fn add_assign(&mut self, other: Self) {
+                *self = *self + other;
+            }
+        }
+
+        impl<T: Coordinate> SubAssign for $point<T> {
+            fn sub_assign(&mut self, other: Self) {
+                *self = *self - other;
+            }
+        }
+
+        /// Scales the vector.
+        impl<T: Coordinate> Mul<T> for $point<T> {
+            type Output = Self;
+
+            fn mul(self, factor: T) -> Self {
+                $point { $($axis: self.$axis * factor),+ }
+            }
+        }
+
+        impl<T: Coordinate + Neg<Output = T>> Neg for $point<T> {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                $point { $($axis: -self.$axis),+ }
+            }
+        }
+    };
+}
+
+vector_ops!(Point { x, y });
+vector_ops!(Point3 { x, y, z });
+
+/// One of the 4 directions along the axes of the plane.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// All directions clockwise, starting with [`Direction::Up`].
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    /// Vector of length 1 pointing in the direction.
+    pub fn offset<T: Coordinate + Neg<Output = T>>(self) -> Point<T> {
+        Point::origin().step(self)
+    }
+
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Direction::Left | Direction::Right)
+    }
+
+    pub fn is_vertical(self) -> bool {
+        !self.is_horizontal()
+    }
+}
+
+/// Straight line between two points, including both of them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Segment<T> {
+    pub start: Point<T>,
+    pub end: Point<T>,
+}
+
+impl<T: Coordinate + Neg<Output = T>> Segment<T> {
+    pub const fn new(start: Point<T>, end: Point<T>) -> Self {
+        Segment { start, end }
+    }
+
+    /// Whether the segments have at least one point in common, i.e. they cross, touch or
+    /// overlap.
+    pub fn intersects(&self, other: &Segment<T>) -> bool {
+        let (start, end) = (orientation(self, other.start), orientation(self, other.end));
+        let (other_start, other_end) =
+            (orientation(other, self.start), orientation(other, self.end));
+        if start != end && other_start != other_end {
+            return true;
+        }
+
+        // the segments are collinear or one end point lies on the line through the other
+        // segment, so they only intersect if that end point lies on the segment itself
+        (start.is_eq() && self.covers(other.start))
+            || (end.is_eq() && self.covers(other.end))
+            || (other_start.is_eq() && other.covers(self.start))
+            || (other_end.is_eq() && other.covers(self.end))
+    }
+
+    /// Whether `point`, which lies on the line through the segment, lies between its ends.
+    fn covers(&self, point: Point<T>) -> bool {
+        let between = |a: T, b: T, value: T| a.min(b) <= value && value <= a.max(b);
+
+        between(self.start.x, self.end.x, point.x) && between(self.start.y, self.end.y, point.y)
+    }
+}
+
+/// Side of the line through `segment` on which `point` lies, equal if it lies on the line.
+fn orientation<T: Coordinate + Neg<Output = T>>(segment: &Segment<T>, point: Point<T>) -> Ordering {
+    let direction = segment.end - segment.start;
+
+    direction.cross(point - segment.start).cmp(&T::ZERO)
+}
+
+#[test]
+fn test_distances() {
+    let a = Point::new(1, -2);
+    let b = Point::new(-3, 5);
+    assert_eq!(a.manhattan_distance(b), 11);
+    assert_eq!(a.chebyshev_distance(b), 7);
+    assert_eq!(
+        Point::new(2usize, 7).manhattan_distance(Point::new(5, 3)),
+        7
+    );
+    assert_eq!(Point3::new(1, 2, 3).manhattan_distance(Point3::origin()), 6);
+}
+
+#[test]
+fn test_directions() {
+    let p = Point::new(0usize, 0);
+    assert_eq!(p.checked_step(Direction::Up), None);
+    assert_eq!(
+        p.step(Direction::Right).step(Direction::Down),
+        Point::new(1, 1)
+    );
+    assert_eq!(Direction::Left.turn_right(), Direction::Up);
+    assert_eq!(Direction::Up.turn_left(), Direction::Left);
+    assert_eq!(Direction::Down.reverse(), Direction::Up);
+    assert_eq!(Direction::Left.offset::<i32>(), Point::new(-1, 0));
+}
+
+#[test]
+fn test_intersects() {
+    let segment = |x1, y1, x2, y2| Segment::new(Point::new(x1, y1), Point::new(x2, y2));
+
+    assert!(segment(0, 0, 4, 4).intersects(&segment(0, 4, 4, 0)));
+    assert!(segment(0, 0, 4, 0).intersects(&segment(2, 0, 2, 3)));
+    assert!(!segment(0, 0, 4, 0).intersects(&segment(2, 1, 2, 3)));
+    assert!(segment(0, 0, 4, 0).intersects(&segment(1, 0, 6, 0)));
+    assert!(segment(0, 0, 4, 0).intersects(&segment(4, 0, 6, 0)));
+    assert!(!segment(0, 0, 4, 0).intersects(&segment(5, 0, 6, 0)));
+    assert!(segment(0, 0, 4, 4).intersects(&segment(2, 2, 2, 2)));
+    assert!(!segment(0, 0, 4, 4).intersects(&segment(2, 3, 2, 3)));
+    assert!(segment(1, 1, 1, 1).intersects(&segment(1, 1, 1, 1)));
+}
@@ -0,0 +1,408 @@
+//! Rectangular grid of cells as found in many puzzle inputs, stored row by row in a single
+//! vector.
+//!
+//! Positions are [points](Point) with the origin in the top left corner, `x` grows to the
+//! right and `y` grows downwards.
+
+use std::{
+    fmt,
+    ops::{Index, IndexMut},
+};
+
+use crate::{input, Direction, Point, SolveError};
+
+/// Offsets of the 4 neighbours of a cell in reading order.
+const NEIGHBOURS_4: [Point<isize>; 4] = [
+    Point::new(0, -1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(0, 1),
+];
+
+/// Offsets of the 8 neighbours of a cell, including the diagonal ones, in reading order.
+const NEIGHBOURS_8: [Point<isize>; 8] = [
+    Point::new(-1, -1),
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(-1, 1),
+    Point::new(0, 1),
+    Point::new(1, 1),
+];
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid of the given size with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
+
+    /// Grid with the cells of `rows`, which must all have the same length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, SolveError> {
+        let width = rows.first().map_or(0, Vec::len);
+        if let Some(y) = rows.iter().position(|r| r.len() != width) {
+            return Err(SolveError::at(y, 0, "all rows must have the same length"));
+        }
+
+        Ok(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses every character of the input into a cell, each line being a row.
+    ///
+    /// `cell` returns a message for characters it does not accept, which is reported at the
+    /// position of the character. The grid must not be empty.
+    pub fn parse<F>(input: &str, mut cell: F) -> Result<Self, SolveError>
+    where
+        F: FnMut(char) -> Result<T, String>,
+    {
+        let rows = input::lines(input)
+            .map(|line| {
+                line.text
+                    .chars()
+                    .enumerate()
+                    .map(|(x, c)| cell(c).map_err(|message| line.error_at(x, message)))
+                    .collect::<Result<Vec<_>, _>>()
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let grid = Grid::from_rows(rows)?;
+        if grid.cells.is_empty() {
+            return Err(SolveError::new("the grid is empty"));
+        }
+
+        Ok(grid)
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, Point { x, y }: Point<usize>) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: Point<usize>) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index_of(position)])
+    }
+
+    pub fn get_mut(&mut self, position: Point<usize>) -> Option<&mut T> {
+        if !self.contains(position) {
+            return None;
+        }
+        let index = self.index_of(position);
+        Some(&mut self.cells[index])
+    }
+
+    /// Position `offset` away from `position` if it is inside the grid.
+    pub fn offset(&self, position: Point<usize>, offset: Point<isize>) -> Option<Point<usize>> {
+        let position = Point::new(
+            position.x.checked_add_signed(offset.x)?,
+            position.y.checked_add_signed(offset.y)?,
+        );
+        self.contains(position).then_some(position)
+    }
+
+    /// Neighbouring position in `direction` if it is inside the grid.
+    pub fn step(&self, position: Point<usize>, direction: Direction) -> Option<Point<usize>> {
+        self.offset(position, direction.offset())
+    }
+
+    /// Positions above, left, right and below of `position` that are inside the grid.
+    pub fn neighbours4(&self, position: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        NEIGHBOURS_4
+            .into_iter()
+            .filter_map(move |d| self.offset(position, d))
+    }
+
+    /// Positions around `position`, including the diagonal ones, that are inside the grid.
+    pub fn neighbours8(&self, position: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        NEIGHBOURS_8
+            .into_iter()
+            .filter_map(move |d| self.offset(position, d))
+    }
+
+    /// All positions in reading order.
+    pub fn positions(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// All cells with their position in reading order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell in reading order that matches `predicate`.
+    pub fn position<P>(&self, predicate: P) -> Option<Point<usize>>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let index = self.cells.iter().position(predicate)?;
+        Some(Point::new(index % self.width, index / self.width))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        // chunks_exact panics for a size of 0
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    pub fn column(
+        &self,
+        x: usize,
+    ) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + Clone {
+        assert!(x < self.width, "column {x} is outside of the grid");
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl DoubleEndedIterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Diagonals that go from the top left to the bottom right, starting with the one in the
+    /// top right corner.
+    pub fn diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (1..=self.diagonal_count()).map(move |d| {
+            // the diagonal starts at x - y = width - d
+            let (x, y) = match self.width.checked_sub(d) {
+                Some(x) => (x, 0),
+                None => (0, d - self.width),
+            };
+            (0..).map_while(move |i| self.get(Point::new(x + i, y + i)))
+        })
+    }
+
+    /// Diagonals that go from the top right to the bottom left, starting with the one in the
+    /// top left corner.
+    pub fn anti_diagonals(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.diagonal_count()).map(move |d| {
+            // the diagonal starts at x + y = d
+            let (x, y) = if d < self.width {
+                (d, 0)
+            } else {
+                (self.width - 1, d - self.width + 1)
+            };
+            (0..=x).map_while(move |i| self.get(Point::new(x - i, y + i)))
+        })
+    }
+
+    /// Grid with the cells mapped by `f`.
+    pub fn map<U, F>(&self, f: F) -> Grid<U>
+    where
+        F: FnMut(&T) -> U,
+    {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// Grid with rows and columns swapped.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |Point { x, y }| Point::new(y, x))
+    }
+
+    /// Grid turned by 90 degrees clockwise.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |Point { x, y }| {
+            Point::new(y, self.height - 1 - x)
+        })
+    }
+
+    /// Grid turned by 90 degrees counterclockwise.
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.height, self.width, |Point { x, y }| {
+            Point::new(self.width - 1 - y, x)
+        })
+    }
+
+    /// Grid mirrored so that left and right are swapped.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.width, self.height, |Point { x, y }| {
+            Point::new(self.width - 1 - x, y)
+        })
+    }
+
+    /// Grid mirrored so that top and bottom are swapped.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.rearranged(self.width, self.height, |Point { x, y }| {
+            Point::new(x, self.height - 1 - y)
+        })
+    }
+
+    /// Grid of the given size where each cell is taken from the position `source` returns
+    /// for it.
+    fn rearranged<F>(&self, width: usize, height: usize, source: F) -> Self
+    where
+        T: Clone,
+        F: Fn(Point<usize>) -> Point<usize>,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point::new(x, y)))
+            .map(|p| self[source(p)].clone())
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    fn diagonal_count(&self) -> usize {
+        if self.cells.is_empty() {
+            0
+        } else {
+            self.width + self.height - 1
+        }
+    }
+
+    fn index_of(&self, Point { x, y }: Point<usize>) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point<usize>) -> &T {
+        assert!(
+            self.contains(position),
+            "position {position:?} is outside of the grid"
+        );
+        &self.cells[self.index_of(position)]
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, position: Point<usize>) -> &mut T {
+        assert!(
+            self.contains(position),
+            "position {position:?} is outside of the grid"
+        );
+        let index = self.index_of(position);
+        &mut self.cells[index]
+    }
+}
+
+/// Rows on separate lines, without a newline after the last one.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{cell}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+fn example() -> Grid<char> {
+    Grid::parse("abc\ndef\n", Ok).unwrap()
+}
+
+#[test]
+fn test_parse() {
+    let grid = example();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[Point::new(2, 1)], 'f');
+    assert_eq!(grid.get(Point::new(3, 0)), None);
+
+    let error = Grid::parse("ab\nc\n", Ok::<_, String>).unwrap_err();
+    assert_eq!(
+        error,
+        SolveError::at(1, 0, "all rows must have the same length")
+    );
+    let error = Grid::parse("ab\nc?\n", |c| match c {
+        '?' => Err("unexpected '?'".to_string()),
+        c => Ok(c),
+    })
+    .unwrap_err();
+    assert_eq!(error, SolveError::at(1, 1, "unexpected '?'"));
+    assert!(Grid::parse("", Ok::<_, String>).is_err());
+}
+
+#[test]
+fn test_neighbours() {
+    let grid = example();
+    let neighbours: Vec<_> = grid
+        .neighbours4(Point::origin())
+        .map(|p| (p.x, p.y))
+        .collect();
+    assert_eq!(neighbours, vec![(1, 0), (0, 1)]);
+    let neighbours: Vec<_> = grid
+        .neighbours8(Point::new(1, 1))
+        .map(|p| (p.x, p.y))
+        .collect();
+    assert_eq!(neighbours, vec![(0, 0), (1, 0), (2, 0), (0, 1), (2, 1)]);
+}
+
+#[test]
+fn test_views() {
+    let grid = example();
+    let collect = |cells: &mut dyn Iterator<Item = &char>| cells.collect::<String>();
+
+    assert_eq!(grid.row(1), ['d', 'e', 'f']);
+    let columns: Vec<_> = grid.columns().map(|mut c| collect(&mut c)).collect();
+    assert_eq!(columns, ["ad", "be", "cf"]);
+    let diagonals: Vec<_> = grid.diagonals().map(|mut d| collect(&mut d)).collect();
+    assert_eq!(diagonals, ["c", "bf", "ae", "d"]);
+    let diagonals: Vec<_> = grid.anti_diagonals().map(|mut d| collect(&mut d)).collect();
+    assert_eq!(diagonals, ["a", "bd", "ce", "f"]);
+}
+
+#[test]
+fn test_transformations() {
+    let grid = example();
+    assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+    assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+    assert_eq!(grid.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+    assert_eq!(grid.flip_horizontal().to_string(), "cba\nfed");
+    assert_eq!(grid.flip_vertical().to_string(), "def\nabc");
+}
@@ -0,0 +1,141 @@
+//! Layout of puzzle inputs: normalised line endings and iterators over lines and blocks of
+//! lines that remember where they are, so parsers can point errors at the input.
+
+use std::{borrow::Cow, fmt::Display, str::FromStr};
+
+use crate::{parse::Cursor, SolveError};
+
+/// Replaces `\r\n` line endings with `\n` and makes a non-empty input end with exactly one
+/// newline.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let trimmed = input.trim_end_matches(['\r', '\n']);
+    if trimmed.is_empty() {
+        return Cow::Borrowed("");
+    }
+    if !trimmed.contains('\r') && input.len() == trimmed.len() + 1 {
+        return Cow::Borrowed(input);
+    }
+
+    Cow::Owned(trimmed.replace("\r\n", "\n") + "\n")
+}
+
+/// Line of the input together with its 0-based index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Line<'a> {
+    pub index: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Line<'a> {
+    /// Whether the line is empty or only has whitespace.
+    pub fn is_blank(&self) -> bool {
+        self.text.trim().is_empty()
+    }
+
+    /// Parses the whole line, ignoring surrounding whitespace.
+    pub fn parse<T>(&self) -> Result<T, SolveError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let token = self.text.trim();
+        token
+            .parse()
+            .map_err(|err| self.error_at_token(token, format!("invalid value '{token}': {err}")))
+    }
+
+    /// Cursor that reads the line from its start.
+    pub fn cursor(&self) -> Cursor<'a> {
+        Cursor::new(*self)
+    }
+
+    /// Splits a `key: value` line into the key and a cursor at the start of the value,
+    /// whitespace before the key and the value is skipped.
+    pub fn key_value(&self) -> Result<(&'a str, Cursor<'a>), SolveError> {
+        let mut cursor = self.cursor();
+        cursor.skip_whitespace();
+        let key = cursor.until(":")?;
+        cursor.skip_whitespace();
+
+        Ok((key, cursor))
+    }
+
+    /// Cursor at the start of the value of a `key: value` line, fails if the line has a
+    /// different key.
+    pub fn value_of(&self, key: &str) -> Result<Cursor<'a>, SolveError> {
+        let mut cursor = self.cursor();
+        cursor.skip_whitespace();
+        cursor.expect(key)?;
+        cursor.expect(":")?;
+        cursor.skip_whitespace();
+
+        Ok(cursor)
+    }
+
+    /// Error at the 0-based `column` of the line.
+    pub fn error_at(&self, column: usize, message: impl Into<String>) -> SolveError {
+        SolveError::at(self.index, column, message)
+    }
+
+    /// Error at `token`, which has to be a slice of the line.
+    pub fn error_at_token(&self, token: &str, message: impl Into<String>) -> SolveError {
+        SolveError::at_token(self.index, self.text, token, message)
+    }
+}
+
+/// Consecutive lines that are separated from other blocks by blank lines.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Block<'a> {
+    lines: Vec<Line<'a>>,
+}
+
+impl<'a> Block<'a> {
+    /// Lines of the block, which are never blank.
+    pub fn lines(&self) -> &[Line<'a>] {
+        &self.lines
+    }
+
+    /// Error pointing at the start of the block.
+    pub fn error(&self, message: impl Into<String>) -> SolveError {
+        self.lines[0].error_at(0, message)
+    }
+}
+
+/// Lines of the input, including blank ones.
+pub fn lines(input: &str) -> impl Iterator<Item = Line<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(index, text)| Line { index, text })
+}
+
+/// Blocks of the input, any number of blank lines separates two blocks.
+pub fn blocks(input: &str) -> impl Iterator<Item = Block<'_>> {
+    let mut lines = lines(input).peekable();
+
+    std::iter::from_fn(move || {
+        while lines.next_if(Line::is_blank).is_some() {}
+        let block: Vec<Line> = std::iter::from_fn(|| lines.next_if(|l| !l.is_blank())).collect();
+
+        (!block.is_empty()).then_some(Block { lines: block })
+    })
+}
+
+#[test]
+fn test_normalize() {
+    assert_eq!(normalize("a\r\nb\r\n\r\n"), "a\nb\n");
+    assert_eq!(normalize("a\nb"), "a\nb\n");
+    assert!(matches!(normalize("a\nb\n"), Cow::Borrowed(_)));
+    assert_eq!(normalize("\n\n"), "");
+}
+
+#[test]
+fn test_blocks() {
+    let input = "a\nb\n\n\n  \nc\n";
+
+    let blocks: Vec<Vec<(usize, &str)>> = blocks(input)
+        .map(|b| b.lines().iter().map(|l| (l.index, l.text)).collect())
+        .collect();
+
+    assert_eq!(blocks, vec![vec![(0, "a"), (1, "b")], vec![(5, "c")]]);
+}
@@ -0,0 +1,25 @@
+//! Building blocks shared by the Advent of Code crates of all years.
+
+mod answer;
+mod error;
+mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod number_theory;
+mod params;
+pub mod parse;
+mod registry;
+pub mod search;
+mod solution;
+
+pub use answer::Answer;
+pub use error::{Error, Location, Part, SolveError};
+pub use geometry::{Direction, Point, Point3, Segment};
+pub use grid::Grid;
+pub use params::{parse_assignment, Param, Params};
+pub use registry::{registered, Registration};
+pub use solution::{find_day, Answers, Parsed, PartAnswer, Puzzle, Solution, StepHook};
+
+#[doc(hidden)]
+pub use inventory;
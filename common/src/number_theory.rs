@@ -0,0 +1,114 @@
+//! Divisibility and congruences, e.g. to find when several cycles line up.
+
+/// Greatest common divisor, `gcd(0, 0)` is 0.
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Least common multiple, it is 0 if one of the numbers is 0.
+///
+/// Panics if the result does not fit into `u64`.
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return 0;
+    }
+
+    (a / gcd(a, b))
+        .checked_mul(b)
+        .unwrap_or_else(|| panic!("lcm of {a} and {b} overflows"))
+}
+
+/// Greatest common divisor `g` of `a` and `b` together with `x` and `y` such that
+/// `a * x + b * y = g`.
+///
+/// `g` is never negative.
+pub fn extended_gcd(a: i64, b: i64) -> (i64, i64, i64) {
+    let (g, x, y) = extended_gcd_wide(a.into(), b.into());
+    let narrow = |n: i128| i64::try_from(n).expect("coefficients are bounded by the inputs");
+
+    (narrow(g), narrow(x), narrow(y))
+}
+
+fn extended_gcd_wide(a: i128, b: i128) -> (i128, i128, i128) {
+    let (mut old_r, mut r) = (a, b);
+    let (mut old_x, mut x) = (1, 0);
+    let (mut old_y, mut y) = (0, 1);
+
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_x, x) = (x, old_x - quotient * x);
+        (old_y, y) = (y, old_y - quotient * y);
+    }
+
+    if old_r < 0 {
+        (-old_r, -old_x, -old_y)
+    } else {
+        (old_r, old_x, old_y)
+    }
+}
+
+/// Smallest non-negative `x` with `x ≡ remainder (mod modulus)` for all given
+/// `(remainder, modulus)` pairs, together with the least common multiple of the moduli, after
+/// which the solutions repeat.
+///
+/// The moduli do not have to be coprime. Returns `None` if the congruences contradict each
+/// other or the result does not fit into `i64`. Panics if a modulus is not positive.
+pub fn chinese_remainder<I>(congruences: I) -> Option<(i64, i64)>
+where
+    I: IntoIterator<Item = (i64, i64)>,
+{
+    // every number solves x ≡ 0 (mod 1)
+    let (mut x, mut period): (i128, i128) = (0, 1);
+
+    for (remainder, modulus) in congruences {
+        assert!(modulus > 0, "modulus {modulus} must be positive");
+        let modulus = i128::from(modulus);
+        let remainder = i128::from(remainder).rem_euclid(modulus);
+
+        // find k with x + period * k ≡ remainder (mod modulus)
+        let (g, inverse, _) = extended_gcd_wide(period, modulus);
+        let difference = remainder - x;
+        if difference % g != 0 {
+            return None;
+        }
+        let k = (difference / g % (modulus / g) * inverse).rem_euclid(modulus / g);
+
+        let combined_period = period / g * modulus;
+        x = (x + period * k).rem_euclid(combined_period);
+        period = i64::try_from(combined_period).ok()?.into();
+    }
+
+    Some((x.try_into().ok()?, period.try_into().ok()?))
+}
+
+#[test]
+fn test_gcd_lcm() {
+    assert_eq!(gcd(12, 18), 6);
+    assert_eq!(gcd(0, 7), 7);
+    assert_eq!(lcm(4, 6), 12);
+    assert_eq!(lcm(0, 6), 0);
+    assert_eq!([2, 3, 4, 5].into_iter().fold(1, lcm), 60);
+}
+
+#[test]
+fn test_extended_gcd() {
+    for (a, b) in [(240, 46), (-240, 46), (7, 0), (0, -7), (17, 5)] {
+        let (g, x, y) = extended_gcd(a, b);
+        assert_eq!(g, gcd(a.unsigned_abs(), b.unsigned_abs()) as i64);
+        assert_eq!(a * x + b * y, g);
+    }
+}
+
+#[test]
+fn test_chinese_remainder() {
+    assert_eq!(chinese_remainder([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+    assert_eq!(chinese_remainder([(2, 4), (4, 6)]), Some((10, 12)));
+    assert_eq!(chinese_remainder([(1, 4), (2, 6)]), None);
+    assert_eq!(chinese_remainder([(-1, 5)]), Some((4, 5)));
+    assert_eq!(chinese_remainder([]), Some((0, 1)));
+}
@@ -0,0 +1,97 @@
+use std::{collections::BTreeMap, fmt};
+
+use crate::SolveError;
+
+/// Value the puzzle description fixes but that differs for its examples, e.g. the number of
+/// rounds to simulate.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Param {
+    pub name: &'static str,
+    /// Value the solution uses, which is the one of the puzzle unless it was overridden.
+    pub value: i64,
+    pub description: &'static str,
+}
+
+impl fmt::Display for Param {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}={}", self.name, self.value)
+    }
+}
+
+/// Values of parameters given by name, e.g. on the command line as `--param row=10`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Params(BTreeMap<String, i64>);
+
+impl Params {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set(&mut self, name: impl Into<String>, value: i64) {
+        self.0.insert(name.into(), value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.0.keys().map(String::as_str)
+    }
+
+    /// Value of the parameter `name` converted to the type of `default`, which is returned if
+    /// the parameter was not given.
+    pub fn get<T>(&self, name: &str, default: T) -> Result<T, SolveError>
+    where
+        T: TryFrom<i64>,
+    {
+        match self.0.get(name) {
+            Some(&value) => T::try_from(value)
+                .map_err(|_| SolveError::new(format!("{value} is out of range for {name}"))),
+            None => Ok(default),
+        }
+    }
+}
+
+impl FromIterator<(String, i64)> for Params {
+    fn from_iter<I: IntoIterator<Item = (String, i64)>>(iter: I) -> Self {
+        Params(iter.into_iter().collect())
+    }
+}
+
+/// Parses an assignment of the form `name=value`.
+pub fn parse_assignment(assignment: &str) -> Result<(String, i64), String> {
+    let (name, value) = assignment
+        .split_once('=')
+        .ok_or_else(|| format!("'{assignment}' must have the form name=value"))?;
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(format!(
+            "'{assignment}' is missing the name of the parameter"
+        ));
+    }
+    let value = value
+        .trim()
+        .replace('_', "")
+        .parse()
+        .map_err(|err| format!("invalid value of {name}: {err}"))?;
+
+    Ok((name.to_string(), value))
+}
+
+#[test]
+fn test_parse_assignment() {
+    assert_eq!(parse_assignment("row=10"), Ok(("row".to_string(), 10)));
+    assert_eq!(
+        parse_assignment("rounds = 1_000"),
+        Ok(("rounds".to_string(), 1000))
+    );
+    assert!(parse_assignment("row").is_err());
+    assert!(parse_assignment("=10").is_err());
+    assert!(parse_assignment("row=ten").is_err());
+
+    let params: Params = [parse_assignment("row=-1").unwrap()].into_iter().collect();
+    assert_eq!(params.get("row", 0_i64), Ok(-1));
+    assert!(params.get("row", 0_usize).is_err());
+    assert_eq!(params.get("rounds", 20_usize), Ok(20));
+}
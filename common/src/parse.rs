@@ -0,0 +1,199 @@
+//! Primitives to read a line of the input piece by piece, failures point at the line and
+//! column where reading went wrong.
+
+use std::{fmt::Display, str::FromStr};
+
+use crate::{input::Line, SolveError};
+
+/// Reads a [`Line`] from left to right.
+///
+/// ```ignore
+/// // Monkey 3: 79, 98
+/// let mut cursor = line.cursor();
+/// cursor.expect("Monkey ")?;
+/// let number: u8 = cursor.number()?;
+/// cursor.expect(": ")?;
+/// let items: Vec<u64> = cursor.separated(", ", Cursor::number)?;
+/// cursor.end()?;
+/// ```
+#[derive(Debug, Clone, Copy)]
+pub struct Cursor<'a> {
+    line: Line<'a>,
+    /// Byte offset of the first unread character.
+    offset: usize,
+}
+
+impl<'a> Cursor<'a> {
+    pub fn new(line: Line<'a>) -> Self {
+        Cursor { line, offset: 0 }
+    }
+
+    /// Part of the line that has not been read yet.
+    pub fn rest(&self) -> &'a str {
+        &self.line.text[self.offset..]
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rest().is_empty()
+    }
+
+    pub fn peek(&self) -> Option<char> {
+        self.rest().chars().next()
+    }
+
+    /// Reads `literal` if the rest of the line starts with it.
+    pub fn eat(&mut self, literal: &str) -> bool {
+        let found = self.rest().starts_with(literal);
+        if found {
+            self.offset += literal.len();
+        }
+        found
+    }
+
+    /// Reads `literal`, failing if the rest of the line does not start with it.
+    pub fn expect(&mut self, literal: &str) -> Result<(), SolveError> {
+        if self.eat(literal) {
+            Ok(())
+        } else {
+            Err(self.error(format!("expected '{literal}'")))
+        }
+    }
+
+    /// Reads the longest prefix whose characters all match `predicate`, which may be empty.
+    pub fn take_while<P>(&mut self, mut predicate: P) -> &'a str
+    where
+        P: FnMut(char) -> bool,
+    {
+        let rest = self.rest();
+        let length = rest.find(|c| !predicate(c)).unwrap_or(rest.len());
+        self.offset += length;
+
+        &rest[..length]
+    }
+
+    pub fn skip_whitespace(&mut self) {
+        self.take_while(char::is_whitespace);
+    }
+
+    /// Reads everything up to the next whitespace, failing if that is nothing.
+    pub fn word(&mut self) -> Result<&'a str, SolveError> {
+        let word = self.take_while(|c| !c.is_whitespace());
+        if word.is_empty() {
+            return Err(self.error("expected a word"));
+        }
+
+        Ok(word)
+    }
+
+    /// Reads everything up to `separator` and the separator itself.
+    pub fn until(&mut self, separator: &str) -> Result<&'a str, SolveError> {
+        let rest = self.rest();
+        let length = rest
+            .find(separator)
+            .ok_or_else(|| self.error(format!("expected '{separator}'")))?;
+        self.offset += length + separator.len();
+
+        Ok(&rest[..length])
+    }
+
+    /// Reads everything that is left.
+    pub fn take_rest(&mut self) -> &'a str {
+        let rest = self.rest();
+        self.offset = self.line.text.len();
+
+        rest
+    }
+
+    /// Reads an integer with an optional sign.
+    pub fn number<T>(&mut self) -> Result<T, SolveError>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let start = *self;
+        if !self.eat("-") {
+            self.eat("+");
+        }
+        if self.take_while(|c| c.is_ascii_digit()).is_empty() {
+            *self = start;
+            return Err(self.error("expected a number"));
+        }
+
+        let token = &start.rest()[..self.offset - start.offset];
+        token.parse().map_err(|err| {
+            start
+                .line
+                .error_at_token(token, format!("invalid number '{token}': {err}"))
+        })
+    }
+
+    /// Reads at least one item, the items are separated by `separator`.
+    pub fn separated<T, F>(&mut self, separator: &str, mut item: F) -> Result<Vec<T>, SolveError>
+    where
+        F: FnMut(&mut Self) -> Result<T, SolveError>,
+    {
+        let mut items = vec![item(self)?];
+        while self.eat(separator) {
+            items.push(item(self)?);
+        }
+
+        Ok(items)
+    }
+
+    /// Fails if the line has not been read completely.
+    pub fn end(&self) -> Result<(), SolveError> {
+        match self.rest() {
+            "" => Ok(()),
+            rest => Err(self.error(format!("unexpected '{rest}'"))),
+        }
+    }
+
+    /// Error at the first unread character.
+    pub fn error(&self, message: impl Into<String>) -> SolveError {
+        self.line.error_at_token(self.rest(), message)
+    }
+}
+
+#[cfg(test)]
+fn line(text: &str) -> Line<'_> {
+    Line { index: 2, text }
+}
+
+#[test]
+fn test_numbers() {
+    let mut cursor = Cursor::new(line("items: 79, -98, +3"));
+    cursor.expect("items: ").unwrap();
+    assert_eq!(cursor.separated(", ", Cursor::number), Ok(vec![79, -98, 3]));
+    assert_eq!(cursor.end(), Ok(()));
+
+    let mut cursor = Cursor::new(line("x=300"));
+    cursor.expect("x=").unwrap();
+    assert_eq!(
+        cursor.number::<u8>().unwrap_err(),
+        SolveError::at(
+            2,
+            2,
+            "invalid number '300': number too large to fit in target type"
+        )
+    );
+    let mut cursor = Cursor::new(line("x=-"));
+    cursor.expect("x=").unwrap();
+    assert_eq!(
+        cursor.number::<i8>(),
+        Err(SolveError::at(2, 2, "expected a number"))
+    );
+    assert_eq!(cursor.rest(), "-");
+}
+
+#[test]
+fn test_errors() {
+    let mut cursor = Cursor::new(line("Valve AA has"));
+    assert_eq!(
+        cursor.expect("Tunnel"),
+        Err(SolveError::at(2, 0, "expected 'Tunnel'"))
+    );
+    assert_eq!(cursor.until(" "), Ok("Valve"));
+    assert_eq!(cursor.word(), Ok("AA"));
+    assert_eq!(cursor.end(), Err(SolveError::at(2, 8, "unexpected ' has'")));
+    assert_eq!(cursor.until(":"), Err(SolveError::at(2, 8, "expected ':'")));
+}
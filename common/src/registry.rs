@@ -0,0 +1,36 @@
+use crate::Puzzle;
+
+/// Entry of the registry of all solutions, added by [`register!`](crate::register).
+#[doc(hidden)]
+pub struct Registration(pub &'static dyn Puzzle);
+
+inventory::collect!(Registration);
+
+/// Adds a solution to the registry, so it can be looked up by [`registered`] without listing it
+/// anywhere else.
+///
+/// ```ignore
+/// pub struct Day06;
+///
+/// aoc_common::register!(Day06);
+/// ```
+#[macro_export]
+macro_rules! register {
+    ($puzzle:expr) => {
+        $crate::inventory::submit! {
+            $crate::Registration(&$puzzle)
+        }
+    };
+}
+
+/// All registered solutions of `year`, ordered by day.
+pub fn registered(year: u16) -> Vec<&'static dyn Puzzle> {
+    let mut days: Vec<&'static dyn Puzzle> = inventory::iter::<Registration>
+        .into_iter()
+        .map(|r| r.0)
+        .filter(|p| p.year() == year)
+        .collect();
+    days.sort_by_key(|p| p.day());
+
+    days
+}
@@ -0,0 +1,197 @@
+//! Shortest paths in graphs that are given by a function returning the neighbours of a node,
+//! so the graph never has to be built up front.
+//!
+//! All searches start at several nodes at once and stop at the first node that is a goal.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+/// Path found by a search, from a start node to a goal node, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+impl<N, C> Path<N, C> {
+    pub fn start(&self) -> &N {
+        self.nodes.first().expect("paths are never empty")
+    }
+
+    pub fn goal(&self) -> &N {
+        self.nodes.last().expect("paths are never empty")
+    }
+}
+
+/// Breadth-first search for the path with the fewest steps, its cost is the number of steps.
+pub fn bfs<N, S, F, I, G>(starts: S, mut neighbours: F, mut is_goal: G) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = N>,
+    G: FnMut(&N) -> bool,
+{
+    let mut predecessors: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = predecessors.entry(start.clone()) {
+            entry.insert(None);
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            let nodes = reconstruct_path(&predecessors, node);
+            return Some(Path {
+                cost: nodes.len() - 1,
+                nodes,
+            });
+        }
+
+        for neighbour in neighbours(&node) {
+            if let Entry::Vacant(entry) = predecessors.entry(neighbour.clone()) {
+                entry.insert(Some(node.clone()));
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's search for the cheapest path, `neighbours` returns the cost of the edge to each
+/// neighbour, which must not be negative.
+pub fn dijkstra<N, C, S, F, I, G>(starts: S, neighbours: F, is_goal: G) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    G: FnMut(&N) -> bool,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search for the cheapest path, `neighbours` returns the cost of the edge to each
+/// neighbour, which must not be negative.
+///
+/// `heuristic` estimates the cost from a node to the closest goal. The path is only
+/// guaranteed to be the cheapest if it never overestimates that cost.
+pub fn astar<N, C, S, F, I, H, G>(
+    starts: S,
+    mut neighbours: F,
+    mut heuristic: H,
+    mut is_goal: G,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    S: IntoIterator<Item = N>,
+    F: FnMut(&N) -> I,
+    I: IntoIterator<Item = (N, C)>,
+    H: FnMut(&N) -> C,
+    G: FnMut(&N) -> bool,
+{
+    // cheapest known cost to reach each node and the node it was reached from
+    let mut best: HashMap<N, (C, Option<N>)> = HashMap::new();
+    // the index breaks ties without requiring the nodes to be ordered
+    let mut queue = BinaryHeap::new();
+    let mut nodes = Vec::new();
+
+    for start in starts {
+        if let Entry::Vacant(entry) = best.entry(start.clone()) {
+            entry.insert((C::default(), None));
+            queue.push(Reverse((heuristic(&start), C::default(), nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let node = nodes[index].clone();
+        if best[&node].0 < cost {
+            // the node was reached more cheaply after it was queued
+            continue;
+        }
+        if is_goal(&node) {
+            let predecessors = best.into_iter().map(|(n, (_, p))| (n, p)).collect();
+            return Some(Path {
+                cost,
+                nodes: reconstruct_path(&predecessors, node),
+            });
+        }
+
+        for (neighbour, edge_cost) in neighbours(&node) {
+            let neighbour_cost = cost + edge_cost;
+            match best.entry(neighbour.clone()) {
+                Entry::Occupied(entry) if entry.get().0 <= neighbour_cost => continue,
+                Entry::Occupied(mut entry) => {
+                    entry.insert((neighbour_cost, Some(node.clone())));
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert((neighbour_cost, Some(node.clone())));
+                }
+            }
+            let estimate = neighbour_cost + heuristic(&neighbour);
+            queue.push(Reverse((estimate, neighbour_cost, nodes.len())));
+            nodes.push(neighbour);
+        }
+    }
+
+    None
+}
+
+/// Nodes from a start node to `goal` by following the predecessors, which are `None` for
+/// start nodes.
+fn reconstruct_path<N>(predecessors: &HashMap<N, Option<N>>, goal: N) -> Vec<N>
+where
+    N: Clone + Eq + Hash,
+{
+    let mut path = vec![goal];
+    while let Some(predecessor) = &predecessors[path.last().unwrap()] {
+        path.push(predecessor.clone());
+    }
+    path.reverse();
+
+    path
+}
+
+#[cfg(test)]
+fn line_neighbours(&n: &i32) -> Vec<(i32, u32)> {
+    // steps of 1 are expensive, steps of 3 cheap
+    vec![(n + 1, 5), (n - 1, 5), (n + 3, 1), (n - 3, 1)]
+}
+
+#[test]
+fn test_bfs() {
+    let path = bfs([0], |&n: &i32| [n + 1, n - 1, n + 3, n - 3], |&n| n == 5).unwrap();
+    assert_eq!(path.cost, 3);
+    assert_eq!((*path.start(), *path.goal()), (0, 5));
+
+    let path = bfs([0, 4], |&n: &i32| [n + 1, n - 1], |&n| n == 6).unwrap();
+    assert_eq!(path.nodes, vec![4, 5, 6]);
+
+    let path = bfs([0], |&n: &i32| (n < 10).then_some(n + 2), |&n| n == 5);
+    assert_eq!(path, None);
+}
+
+#[test]
+fn test_dijkstra() {
+    let path = dijkstra([0], line_neighbours, |&n| n == 5).unwrap();
+    assert_eq!(path.cost, 7);
+    assert_eq!(path.nodes.len(), 4);
+    assert_eq!((*path.start(), *path.goal()), (0, 5));
+}
+
+#[test]
+fn test_astar() {
+    let heuristic = |&n: &i32| n.abs_diff(5) / 3;
+    let path = astar([0], line_neighbours, heuristic, |&n| n == 5).unwrap();
+    assert_eq!(path.cost, 7);
+}
@@ -0,0 +1,296 @@
+use std::{
+    ops::Deref,
+    time::{Duration, Instant},
+};
+
+use crate::{Answer, Error, Param, Params, Part, SolveError};
+
+/// Solution for the puzzle of a single day.
+///
+/// The input is parsed once by [`Solution::parse`] and the result is handed to both parts.
+pub trait Solution {
+    /// Year of the event the puzzle belongs to.
+    const YEAR: u16;
+    /// Day of the puzzle, starting at 1.
+    const DAY: u8;
+    /// Title of the puzzle as shown on the website.
+    const TITLE: &'static str;
+
+    /// Parsed representation of the puzzle input.
+    type Input;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, SolveError>;
+
+    fn part_one(&self, input: &Self::Input) -> Result<Answer, SolveError>;
+
+    fn part_two(&self, input: &Self::Input) -> Result<Answer, SolveError>;
+
+    /// Parameters of the puzzle with the values this solution uses.
+    fn params(&self) -> Vec<Param> {
+        Vec::new()
+    }
+
+    /// Copy of the solution that uses the given parameters, the others keep their value.
+    ///
+    /// Only called with names of [`Solution::params`].
+    fn with_params(&self, _params: &Params) -> Result<Self, SolveError>
+    where
+        Self: Sized,
+    {
+        Err(SolveError::new("the puzzle has no parameters"))
+    }
+}
+
+/// Answer of a single part and the wall-clock time it took to compute it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartAnswer {
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Answers of the parts of a puzzle that were solved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Answers {
+    /// Wall-clock time it took to parse the input.
+    pub parse_elapsed: Duration,
+    pub part_one: Option<PartAnswer>,
+    pub part_two: Option<PartAnswer>,
+}
+
+impl Answers {
+    pub fn get(&self, part: Part) -> Option<&PartAnswer> {
+        match part {
+            Part::One => self.part_one.as_ref(),
+            Part::Two => self.part_two.as_ref(),
+        }
+    }
+}
+
+/// Object safe view on a [`Solution`] so that days with different input types can be
+/// kept in a single registry.
+pub trait Puzzle: Sync {
+    fn year(&self) -> u16;
+
+    fn day(&self) -> u8;
+
+    fn title(&self) -> &'static str;
+
+    /// Parameters of the puzzle with their default values.
+    fn params(&self) -> Vec<Param>;
+
+    /// Parses the [normalised](crate::input::normalize) input, so its parts can be solved
+    /// separately, e.g. for benchmarks.
+    ///
+    /// The parts are solved with the given parameters, it is an error to give one the puzzle
+    /// does not have.
+    fn parse_input<'a>(
+        &'a self,
+        input: &str,
+        params: &Params,
+    ) -> Result<Box<dyn Parsed + 'a>, Error>;
+
+    /// Parses the input and solves the given parts.
+    fn solve(&self, input: &str, parts: &[Part], params: &Params) -> Result<Answers, Error> {
+        self.solve_with(input, parts, params, &mut |_, step| step())
+    }
+
+    /// Like [`Puzzle::solve`], but every step is run by `around`, e.g. to measure it.
+    ///
+    /// `around` gets the part the step solves, `None` for parsing, and has to run the step
+    /// exactly once.
+    fn solve_with(
+        &self,
+        input: &str,
+        parts: &[Part],
+        params: &Params,
+        around: &mut StepHook,
+    ) -> Result<Answers, Error> {
+        let mut parsed = None;
+        let mut parse_elapsed = Duration::ZERO;
+        around(None, &mut || {
+            let start = Instant::now();
+            parsed = Some(self.parse_input(input, params));
+            parse_elapsed = start.elapsed();
+        });
+        let parsed = parsed.expect("the parse step has been run")?;
+        let mut answers = Answers {
+            parse_elapsed,
+            part_one: None,
+            part_two: None,
+        };
+
+        for &part in parts {
+            let mut solved = None;
+            around(Some(part), &mut || {
+                let start = Instant::now();
+                let answer = parsed.solve(part);
+                solved = Some((answer, start.elapsed()));
+            });
+            let (answer, elapsed) = solved.expect("the part has been solved");
+            let answer = Some(PartAnswer {
+                answer: answer?,
+                elapsed,
+            });
+
+            match part {
+                Part::One => answers.part_one = answer,
+                Part::Two => answers.part_two = answer,
+            }
+        }
+
+        Ok(answers)
+    }
+}
+
+/// Runs a step of [`Puzzle::solve_with`], given the part it solves or `None` for parsing.
+pub type StepHook<'a> = dyn FnMut(Option<Part>, &mut dyn FnMut()) + 'a;
+
+/// Input of a [`Puzzle`] that has already been parsed.
+pub trait Parsed {
+    fn solve(&self, part: Part) -> Result<Answer, Error>;
+}
+
+struct ParsedInput<'a, S: Solution> {
+    solution: Configured<'a, S>,
+    input: S::Input,
+}
+
+/// Solution with its default parameters or a copy with overridden ones.
+enum Configured<'a, S> {
+    Default(&'a S),
+    Overridden(S),
+}
+
+impl<S> Deref for Configured<'_, S> {
+    type Target = S;
+
+    fn deref(&self) -> &S {
+        match self {
+            Configured::Default(solution) => solution,
+            Configured::Overridden(solution) => solution,
+        }
+    }
+}
+
+impl<S: Solution> Parsed for ParsedInput<'_, S> {
+    fn solve(&self, part: Part) -> Result<Answer, Error> {
+        match part {
+            Part::One => self.solution.part_one(&self.input),
+            Part::Two => self.solution.part_two(&self.input),
+        }
+        .map_err(|source| Error {
+            year: S::YEAR,
+            day: S::DAY,
+            part: Some(part),
+            source,
+        })
+    }
+}
+
+impl<S> Puzzle for S
+where
+    S: Solution + Sync,
+{
+    fn year(&self) -> u16 {
+        S::YEAR
+    }
+
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn params(&self) -> Vec<Param> {
+        Solution::params(self)
+    }
+
+    fn parse_input<'a>(
+        &'a self,
+        input: &str,
+        params: &Params,
+    ) -> Result<Box<dyn Parsed + 'a>, Error> {
+        let error = |source| Error {
+            year: S::YEAR,
+            day: S::DAY,
+            part: None,
+            source,
+        };
+        let solution = if params.is_empty() {
+            Configured::Default(self)
+        } else {
+            check_params(&Solution::params(self), params).map_err(error)?;
+            Configured::Overridden(self.with_params(params).map_err(error)?)
+        };
+
+        let input = crate::input::normalize(input);
+        let input = solution.parse(&input).map_err(error)?;
+
+        Ok(Box::new(ParsedInput { solution, input }))
+    }
+}
+
+/// Fails for the first parameter in `params` that is not `declared`, listing the declared ones
+/// with their defaults and descriptions.
+fn check_params(declared: &[Param], params: &Params) -> Result<(), SolveError> {
+    let Some(unknown) = params
+        .names()
+        .find(|name| declared.iter().all(|p| p.name != *name))
+    else {
+        return Ok(());
+    };
+
+    if declared.is_empty() {
+        return Err(SolveError::new(format!(
+            "unknown parameter '{unknown}', the puzzle has no parameters"
+        )));
+    }
+    let declared: Vec<_> = declared
+        .iter()
+        .map(|p| format!("{p} ({})", p.description))
+        .collect();
+    Err(SolveError::new(format!(
+        "unknown parameter '{unknown}', the puzzle has {}",
+        declared.join(", ")
+    )))
+}
+
+/// Looks up the puzzle for `day` in a registry of puzzles.
+pub fn find_day(days: &[&'static dyn Puzzle], day: u8) -> Option<&'static dyn Puzzle> {
+    days.iter().find(|p| p.day() == day).copied()
+}
+
+#[test]
+fn test_check_params_lists_declared_params() {
+    let declared = [
+        Param {
+            name: "row",
+            value: 10,
+            description: "row to count",
+        },
+        Param {
+            name: "rounds",
+            value: 20,
+            description: "rounds to simulate",
+        },
+    ];
+    let params: Params = [("row".to_string(), 2)].into_iter().collect();
+    assert_eq!(check_params(&declared, &params), Ok(()));
+
+    let params: Params = [("rows".to_string(), 2)].into_iter().collect();
+    assert_eq!(
+        check_params(&declared, &params),
+        Err(SolveError::new(
+            "unknown parameter 'rows', the puzzle has row=10 (row to count), \
+             rounds=20 (rounds to simulate)"
+        ))
+    );
+    assert_eq!(
+        check_params(&[], &params),
+        Err(SolveError::new(
+            "unknown parameter 'rows', the puzzle has no parameters"
+        ))
+    );
+}